@@ -1,8 +1,27 @@
 extern crate proc_macro;
-use proc_macro::{TokenStream, TokenTree};
+use proc_macro::{Delimiter, Group, Ident, Literal, Punct, Spacing, Span, TokenStream, TokenTree};
 use proc_macro::Delimiter::Parenthesis;
 
-fn parse_associated_type(more_items: &mut proc_macro::token_stream::IntoIter, mut help_item: TokenTree) -> (String,TokenTree) {
+/**
+Builds a `compile_error!` invocation reporting `message` at `span`.
+
+Emitting this rather than panicking lets rustc point at the offending token.
+*/
+fn compile_error(message: &str, span: Span) -> TokenStream {
+    let mut bang = Punct::new('!', Spacing::Alone);
+    bang.set_span(span);
+    let mut literal = Literal::string(message);
+    literal.set_span(span);
+    let mut group = Group::new(Delimiter::Brace, TokenTree::Literal(literal).into());
+    group.set_span(span);
+    [
+        TokenTree::Ident(Ident::new("compile_error", span)),
+        TokenTree::Punct(bang),
+        TokenTree::Group(group),
+    ].into_iter().collect()
+}
+
+fn parse_associated_type(more_items: &mut proc_macro::token_stream::IntoIter, mut help_item: TokenTree) -> Result<(String,TokenTree), TokenStream> {
     let mut complex_generics = "".to_string();
     //if we get <> in the condition, it is the 'associated type' syntax like <F: Trait<AssociatedType=Bar>`
     //here we need to eat an arbitrary number of tokens until we get balance again
@@ -12,7 +31,7 @@ fn parse_associated_type(more_items: &mut proc_macro::token_stream::IntoIter, mu
     loop {
         match more_items.next() {
             None => {
-                panic!("Expected more `>` near {}",help_item);
+                return Err(compile_error("Expected `>` to close this associated type", help_item.span()));
             }
             Some(TokenTree::Punct(p)) if p.to_string() == ">" => {
                 open_brackets -= 1;
@@ -36,7 +55,7 @@ fn parse_associated_type(more_items: &mut proc_macro::token_stream::IntoIter, mu
             }
         }
     }
-    (complex_generics, help_item)
+    Ok((complex_generics, help_item))
 }
 
 /**
@@ -45,13 +64,15 @@ This parses generic arguments like `<'a, A,B,C>`.  This doesn't support conditio
 # Parameters
 * g: pass in the head here, if it looks like `<` we wil begin parsing generics
 * more_items: In case we need more items, we will get them here
-* help_item: Provides help info in case we need to panic
+* help_item: Provides a span for errors
 
 # Return
 1.  String (like `"<'a, A,B, C>"`)
 2.  New `help_item`
+
+Or a `compile_error!` if the arguments use unsupported syntax.
 */
-fn parse_generics_simple(g: Option<TokenTree>,more_items: &mut proc_macro::token_stream::IntoIter, mut help_item: TokenTree) -> (String,TokenTree) { //("<A,B>",help_item)
+fn parse_generics_simple(g: Option<TokenTree>,more_items: &mut proc_macro::token_stream::IntoIter, mut help_item: TokenTree) -> Result<(String,TokenTree), TokenStream> { //("<A,B>",help_item)
     let mut imp_generics = "".to_string();
     match g {
         Some(TokenTree::Punct(p)) if p.to_string() == "<" => {
@@ -78,26 +99,28 @@ fn parse_generics_simple(g: Option<TokenTree>,more_items: &mut proc_macro::token
                     Some(TokenTree::Punct(p)) if p.to_string() == "<" => {
                         // println!("parsing associated item");
                         //this is the 'associated item' style syntax e.g. <Trait<Item=Foo>>
-                        let parse = parse_associated_type(more_items, help_item);
+                        let parse = parse_associated_type(more_items, help_item)?;
                         imp_generics += &parse.0;
                         help_item = parse.1;
                         // println!("parsed {}", parse.0);
                     }
-                    other => {
-                        panic!("Expected generic arguments near {:?} D", other);
+                    Some(other) => {
+                        return Err(compile_error(&format!("Unsupported syntax `{}` in the field's generic arguments", other), other.span()));
+                    }
+                    None => {
+                        return Err(compile_error("Expected `>` to close the field's generic arguments", help_item.span()));
                     }
                 }
 
             }
-            assert!(imp_generics.ends_with(">"), "Expected generic arguments near {} E", help_item);
-            (imp_generics,help_item)
+            Ok((imp_generics,help_item))
         }
         Some(other) => {
             //generics are pretty much optional
-            ("".to_string(),other)
+            Ok(("".to_string(),other))
         }
         None => {
-            ("".to_string(),help_item)
+            Ok(("".to_string(),help_item))
         }
     }
 }
@@ -108,14 +131,16 @@ This parses generic arguments like `<'a,'b: 'a, A: Sync>`.  This supports condit
 # Parameters
 * g: pass in the head here, if it looks like `<` we wil begin parsing generics
 * more_items: In case we need more items to parse, we will get them here
-* help_item: Provides help info in case we need to panic
+* help_item: Provides a span for errors
 
 # Return
 1.  Complex generics (like `"`<'a,'b: 'a, A: Sync>``)
 2.  Simple genericsl (like `"<'a,'b,A>"`)
 2.  New `help_item`
+
+Or a `compile_error!` if the parameters use unsupported syntax.
  */
-fn parse_generics_complex(g: Option<TokenTree>,more_items: &mut proc_macro::token_stream::IntoIter, help_item: TokenTree) -> (String,String,TokenTree) {
+fn parse_generics_complex(g: Option<TokenTree>,more_items: &mut proc_macro::token_stream::IntoIter, help_item: TokenTree) -> Result<(String,String,TokenTree), TokenStream> {
     let mut complex_generics = "".to_string();
     let mut simple_generics = "".to_string();
     match g {
@@ -130,7 +155,10 @@ fn parse_generics_complex(g: Option<TokenTree>,more_items: &mut proc_macro::toke
 
                 match generics {
                     None => {
-                        break;
+                        return Err(compile_error("Expected `>` to close the generic parameters", last_item.span()));
+                    }
+                    Some(TokenTree::Ident(i)) if i.to_string() == "const" => {
+                        return Err(compile_error("Const generic parameters are not supported", i.span()));
                     }
                     Some(TokenTree::Ident(i)) => {
                         complex_generics += &i.to_string();
@@ -156,7 +184,7 @@ fn parse_generics_complex(g: Option<TokenTree>,more_items: &mut proc_macro::toke
                             let next = more_items.next();
                             match next {
                                 None => {
-                                    assert!(complex_generics.ends_with(","), "Expected `,` near {}",last_item);
+                                    return Err(compile_error("Expected `>` to close the generic parameters", last_item.span()));
                                 }
                                 Some(TokenTree::Ident(i)) => {
                                     complex_generics += &i.to_string();
@@ -175,7 +203,7 @@ fn parse_generics_complex(g: Option<TokenTree>,more_items: &mut proc_macro::toke
                                     break; //inner!
                                 }
                                 Some(TokenTree::Punct(p)) if p.to_string() == "<" => {
-                                    let parsed = parse_associated_type(more_items, last_item);
+                                    let parsed = parse_associated_type(more_items, last_item)?;
                                     complex_generics += &parsed.0;
                                     last_item = parsed.1;
 
@@ -186,37 +214,36 @@ fn parse_generics_complex(g: Option<TokenTree>,more_items: &mut proc_macro::toke
                                     last_item = TokenTree::Punct(p);
                                 }
                                 Some(other) => {
-                                        todo!("{:?}",other)
-                                        }
+                                    return Err(compile_error(&format!("Unsupported syntax `{}` in the bounds of a generic parameter", other), other.span()));
+                                }
                             }
                         }
                     }
-                    other => {
-                        panic!("Expected generic arguments near {:?} A", other);
+                    Some(other) => {
+                        return Err(compile_error(&format!("Unsupported syntax `{}` in the generic parameters; expected lifetimes or type parameters with optional bounds", other), other.span()));
                     }
                 }
             }
-            assert!(complex_generics.ends_with(">"), "Expected generic arguments near {} C", last_item);
             // println!("parsed {} {}",complex_generics, simple_generics);
-            (complex_generics, simple_generics, last_item)
+            Ok((complex_generics, simple_generics, last_item))
         }
         Some(other) => {
             //generics are pretty much optional
-            ("".to_string(),"".to_string(), other)
+            Ok(("".to_string(),"".to_string(), other))
         }
         None => {
-            ("".to_string(), "".to_string(), help_item)
+            Ok(("".to_string(), "".to_string(), help_item))
         }
     }
 }
-fn parse_body(g: Option<TokenTree>, help_item: TokenTree) -> (String,String,String,TokenTree) { //path,vis,imp_generics,new help_item
+fn parse_body(g: Option<TokenTree>, help_item: TokenTree) -> Result<(String,String,String,TokenTree), TokenStream> { //path,vis,imp_generics,new help_item
     let mut path = "".to_string();
     let mut vis = "".to_string();
     let mut imp_generics = "".to_string();
     let mut imp_generics_head = None;
     match g {
         None => {
-            panic!("Expected parenthesis near {}",help_item)
+            Err(compile_error("Expected a tuple struct body with a single field, like `(imp::Foo)`", help_item.span()))
         }
         Some(TokenTree::Group(g)) if g.delimiter() == Parenthesis => {
             /*
@@ -237,6 +264,9 @@ fn parse_body(g: Option<TokenTree>, help_item: TokenTree) -> (String,String,Stri
                         vis += "pub";
                         new_help_item = Some(TokenTree::Ident(i));
                     }
+                    TokenTree::Punct(p) if p.to_string() == "#" => {
+                        return Err(compile_error("Attributes on the field are not supported", p.span()));
+                    }
                     //parse the `(crate)` in `pub(crate)`
                     TokenTree::Group(g) if vis == "pub" => {
                         vis += &g.to_string();
@@ -251,7 +281,7 @@ fn parse_body(g: Option<TokenTree>, help_item: TokenTree) -> (String,String,Stri
                         path += ":";
                         new_help_item = Some(TokenTree::Punct(p));
                     }
-                    TokenTree::Punct(p) if p.to_string() == ";" => {
+                    TokenTree::Punct(p) if p.to_string() == ";" || p.to_string() == "," => {
                         new_help_item = Some(TokenTree::Punct(p));
                         break;
                     }
@@ -268,24 +298,437 @@ fn parse_body(g: Option<TokenTree>, help_item: TokenTree) -> (String,String,Stri
                     }
                 }
             }
-            let mut help_item = new_help_item.expect(&format!("Expected the body of a struct near {}",g));
+            let mut help_item = match new_help_item {
+                Some(item) => item,
+                None => return Err(compile_error("Expected a single field in the tuple struct", g.span())),
+            };
             //try parsing the rest as imp_generics
             //parse as imp_generics
             if let Some(imp_generics_head) = imp_generics_head {
-                let r = parse_generics_simple(Some(imp_generics_head), &mut more_items, help_item);
+                let r = parse_generics_simple(Some(imp_generics_head), &mut more_items, help_item)?;
                 imp_generics = r.0;
                 help_item = r.1;
             }
-            assert!(!path.is_empty());
+            if path.is_empty() {
+                return Err(compile_error("Expected the type of the field", help_item.span()));
+            }
+            //allow a trailing comma, but not a second field
+            for item in more_items {
+                match item {
+                    TokenTree::Punct(p) if p.to_string() == "," => {}
+                    other => {
+                        return Err(compile_error("boil wraps a single field, but this tuple struct has more than one", other.span()));
+                    }
+                }
+            }
 
-            (path,vis,imp_generics,help_item)
+            Ok((path,vis,imp_generics,help_item))
+        }
+        Some(TokenTree::Group(g)) if g.delimiter() == Delimiter::Brace => {
+            Err(compile_error("Structs with named fields are not supported; declare a tuple struct with a single field, like `struct Foo(imp::Foo);`", g.span()))
         }
-
         Some(other) => {
-            panic!("Expected the body of a struct near {}",other);
+            Err(compile_error(&format!("Expected a tuple struct body with a single field, like `(imp::Foo)`, instead of `{}`", other), other.span()))
+        }
+    }
+}
+/**
+A single argument to `#[boil(...)]`, like `scoped`, `name = value` or `name(a, b)`.
+*/
+struct BoilArg {
+    name: Ident,
+    value: BoilArgValue,
+}
+enum BoilArgValue {
+    ///`name`
+    Flag,
+    ///`name = tokens`
+    Assign(TokenStream),
+    ///`name(tokens)`
+    List(Group),
+}
+
+impl BoilArg {
+    ///Splits the attribute arguments on `,` into individual arguments.
+    fn parse_all(attr: TokenStream) -> Result<Vec<BoilArg>, TokenStream> {
+        let mut args = Vec::new();
+        let mut iter = attr.into_iter().peekable();
+        loop {
+            let name = match iter.next() {
+                None => break,
+                Some(TokenTree::Ident(i)) => i,
+                Some(other) => {
+                    return Err(compile_error(&format!("Expected an argument name instead of `{}`", other), other.span()));
+                }
+            };
+            let value = match iter.peek() {
+                Some(TokenTree::Punct(p)) if p.as_char() == '=' => {
+                    let eq = iter.next().unwrap();
+                    let mut value = TokenStream::new();
+                    while let Some(tree) = iter.peek() {
+                        if matches!(tree, TokenTree::Punct(p) if p.as_char() == ',') { break }
+                        value.extend([iter.next().unwrap()]);
+                    }
+                    if value.is_empty() {
+                        return Err(compile_error(&format!("Expected a value for `{}` after `=`", name), eq.span()));
+                    }
+                    BoilArgValue::Assign(value)
+                }
+                Some(TokenTree::Group(g)) if g.delimiter() == Parenthesis => {
+                    let g = g.clone();
+                    iter.next();
+                    BoilArgValue::List(g)
+                }
+                _ => BoilArgValue::Flag,
+            };
+            args.push(BoilArg { name, value });
+            match iter.next() {
+                None => break,
+                Some(TokenTree::Punct(p)) if p.as_char() == ',' => {}
+                Some(other) => {
+                    return Err(compile_error(&format!("Expected `,` between arguments instead of `{}`", other), other.span()));
+                }
+            }
+        }
+        Ok(args)
+    }
+    ///The argument as it was written, for passing it along to another invocation.
+    fn to_tokens(&self) -> TokenStream {
+        let mut tokens: TokenStream = TokenTree::Ident(self.name.clone()).into();
+        match &self.value {
+            BoilArgValue::Flag => {}
+            BoilArgValue::Assign(value) => {
+                tokens.extend([TokenTree::Punct(Punct::new('=', Spacing::Alone))]);
+                tokens.extend(value.clone());
+            }
+            BoilArgValue::List(group) => {
+                tokens.extend([TokenTree::Group(group.clone())]);
+            }
+        }
+        tokens
+    }
+    ///Errors unless the argument is a bare flag.
+    fn expect_flag(&self) -> Result<(), TokenStream> {
+        match self.value {
+            BoilArgValue::Flag => Ok(()),
+            _ => Err(compile_error(&format!("`{}` does not take a value", self.name), self.name.span())),
+        }
+    }
+    ///Errors unless the argument looks like `name = pub(crate)`, returning the visibility.
+    fn expect_visibility(&self) -> Result<String, TokenStream> {
+        match &self.value {
+            BoilArgValue::Assign(value) => {
+                match value.clone().into_iter().next() {
+                    Some(TokenTree::Ident(i)) if i.to_string() == "pub" => Ok(value.to_string()),
+                    Some(other) => Err(compile_error(&format!("Expected a visibility like `pub` or `pub(crate)` for `{}` instead of `{}`", self.name, other), other.span())),
+                    None => unreachable!("Assign values are never empty"),
+                }
+            }
+            _ => Err(compile_error(&format!("Expected a visibility like `{} = pub(crate)`", self.name), self.name.span())),
+        }
+    }
+    ///Errors unless the argument looks like `name = some::path`, returning the path.
+    fn expect_path(&self) -> Result<TokenStream, TokenStream> {
+        match &self.value {
+            BoilArgValue::Assign(value) => {
+                for tree in value.clone() {
+                    match tree {
+                        TokenTree::Ident(_) => {}
+                        TokenTree::Punct(p) if p.as_char() == ':' => {}
+                        other => {
+                            return Err(compile_error(&format!("Expected a path for `{}` instead of `{}`", self.name, other), other.span()));
+                        }
+                    }
+                }
+                Ok(value.clone())
+            }
+            _ => Err(compile_error(&format!("Expected a path like `{} = my_preset`", self.name), self.name.span())),
+        }
+    }
+    ///Errors unless the argument looks like `name(...)`, returning the contents of the parenthesis.
+    fn expect_list(&self) -> Result<TokenStream, TokenStream> {
+        match &self.value {
+            BoilArgValue::List(group) => Ok(group.stream()),
+            _ => Err(compile_error(&format!("Expected a list like `{}(...)`", self.name), self.name.span())),
+        }
+    }
+}
+
+/**
+Behaviors selected by the arguments to `#[boil(...)]` and `#[boil_unsized(...)]`.
+
+Each family of impls is on by default and can be turned off with its `no_` argument.
+*/
+struct BoilArgs {
+    ///`scoped`: the field may be less visible than the wrapper, so don't expose it through Deref
+    scoped: bool,
+    ///AsRef, AsMut
+    as_ref: bool,
+    ///Borrow, BorrowMut
+    borrow: bool,
+    ///From, Into, and the Result conversions built on them
+    from: bool,
+    ///Box, Pin, Arc, Rc, slices, collections and Option
+    projections: bool,
+    ///Deref
+    deref: bool,
+    ///DerefMut
+    deref_mut: bool,
+    ///`derive(...)`: derives to add to the struct
+    derives: Vec<TokenStream>,
+    ///`preset = path`: the preset to expand, and the other arguments to pass along to it
+    preset: Option<(TokenStream, TokenStream)>,
+    ///`conversions = pub(crate)`: visibility of the generated inherent conversion functions, instead of the field's
+    conversions_vis: Option<String>,
+    ///`ops(Add, Sub)`: operator traits to forward to the payload
+    ops: Vec<Ident>,
+    ///`ops(mixed)`: also implement binary operators between the wrapper and the payload
+    mixed_ops: bool,
+    ///`const_new`: const constructor and destructor, if requested
+    const_new: Option<Ident>,
+    ///`iter`: IntoIterator, FromIterator and Extend, if requested
+    iter: Option<Ident>,
+    ///`index`: Index and IndexMut
+    index: bool,
+    ///`future`: Future
+    future: bool,
+    ///`future(output = Type)`: the type the payload's output converts into with Into
+    future_output: Option<String>,
+    ///`io`: Read, Write, Seek, BufRead
+    io: bool,
+}
+
+///An operator that can be forwarded with `ops(...)`.
+struct Op {
+    ///The operator's trait, like `Add`
+    trait_name: &'static str,
+    ///The trait's method, like `add`
+    method: &'static str,
+    ///For binary operators, the assigning trait and its method, like `AddAssign` and `add_assign`
+    assign: Option<(&'static str, &'static str)>,
+}
+///Operators that can be forwarded with `ops(...)`.
+const OPS: &[Op] = &[
+    Op { trait_name: "Add", method: "add", assign: Some(("AddAssign", "add_assign")) },
+    Op { trait_name: "Sub", method: "sub", assign: Some(("SubAssign", "sub_assign")) },
+    Op { trait_name: "Mul", method: "mul", assign: Some(("MulAssign", "mul_assign")) },
+    Op { trait_name: "Div", method: "div", assign: Some(("DivAssign", "div_assign")) },
+    Op { trait_name: "Rem", method: "rem", assign: Some(("RemAssign", "rem_assign")) },
+    Op { trait_name: "BitAnd", method: "bitand", assign: Some(("BitAndAssign", "bitand_assign")) },
+    Op { trait_name: "BitOr", method: "bitor", assign: Some(("BitOrAssign", "bitor_assign")) },
+    Op { trait_name: "BitXor", method: "bitxor", assign: Some(("BitXorAssign", "bitxor_assign")) },
+    Op { trait_name: "Shl", method: "shl", assign: Some(("ShlAssign", "shl_assign")) },
+    Op { trait_name: "Shr", method: "shr", assign: Some(("ShrAssign", "shr_assign")) },
+    Op { trait_name: "Neg", method: "neg", assign: None },
+    Op { trait_name: "Not", method: "not", assign: None },
+];
+///A trait forwarded with `io`.
+struct IoTrait {
+    ///The trait's name, like `Read`
+    name: &'static str,
+    ///Whether `&Wrapper` implements the trait too
+    by_ref: bool,
+    ///The trait's forwarded methods
+    methods: &'static [IoMethod],
+}
+///A method of an [`IoTrait`] that takes `&mut self`.
+struct IoMethod {
+    ///The method's name, like `read`
+    name: &'static str,
+    ///The parameters after `&mut self`
+    params: &'static str,
+    ///The return type
+    ret: &'static str,
+    ///The arguments to forward
+    args: &'static str,
+}
+///Traits forwarded with `io`.
+const IO: &[IoTrait] = &[
+    IoTrait { name: "Read", by_ref: true, methods: &[
+        IoMethod { name: "read", params: "buf: &mut [u8]", ret: "std::io::Result<usize>", args: "buf" },
+        IoMethod { name: "read_vectored", params: "bufs: &mut [std::io::IoSliceMut<'_>]", ret: "std::io::Result<usize>", args: "bufs" },
+        IoMethod { name: "read_to_end", params: "buf: &mut Vec<u8>", ret: "std::io::Result<usize>", args: "buf" },
+        IoMethod { name: "read_to_string", params: "buf: &mut String", ret: "std::io::Result<usize>", args: "buf" },
+        IoMethod { name: "read_exact", params: "buf: &mut [u8]", ret: "std::io::Result<()>", args: "buf" },
+    ] },
+    IoTrait { name: "Write", by_ref: true, methods: &[
+        IoMethod { name: "write", params: "buf: &[u8]", ret: "std::io::Result<usize>", args: "buf" },
+        IoMethod { name: "write_vectored", params: "bufs: &[std::io::IoSlice<'_>]", ret: "std::io::Result<usize>", args: "bufs" },
+        IoMethod { name: "flush", params: "", ret: "std::io::Result<()>", args: "" },
+        IoMethod { name: "write_all", params: "buf: &[u8]", ret: "std::io::Result<()>", args: "buf" },
+        IoMethod { name: "write_fmt", params: "args: std::fmt::Arguments<'_>", ret: "std::io::Result<()>", args: "args" },
+    ] },
+    IoTrait { name: "Seek", by_ref: true, methods: &[
+        IoMethod { name: "seek", params: "pos: std::io::SeekFrom", ret: "std::io::Result<u64>", args: "pos" },
+        IoMethod { name: "rewind", params: "", ret: "std::io::Result<()>", args: "" },
+        IoMethod { name: "stream_position", params: "", ret: "std::io::Result<u64>", args: "" },
+        IoMethod { name: "seek_relative", params: "offset: i64", ret: "std::io::Result<()>", args: "offset" },
+    ] },
+    IoTrait { name: "BufRead", by_ref: false, methods: &[
+        IoMethod { name: "fill_buf", params: "", ret: "std::io::Result<&[u8]>", args: "" },
+        IoMethod { name: "consume", params: "amount: usize", ret: "()", args: "amount" },
+        IoMethod { name: "read_until", params: "byte: u8, buf: &mut Vec<u8>", ret: "std::io::Result<usize>", args: "byte, buf" },
+        IoMethod { name: "skip_until", params: "byte: u8", ret: "std::io::Result<usize>", args: "byte" },
+        IoMethod { name: "read_line", params: "buf: &mut String", ret: "std::io::Result<usize>", args: "buf" },
+    ] },
+];
+impl BoilArgs {
+    fn new(attr: TokenStream) -> Result<Self, TokenStream> {
+        let mut args = BoilArgs {
+            scoped: false,
+            as_ref: true,
+            borrow: true,
+            from: true,
+            projections: true,
+            deref: true,
+            deref_mut: true,
+            derives: Vec::new(),
+            preset: None,
+            conversions_vis: None,
+            ops: Vec::new(),
+            mixed_ops: false,
+            const_new: None,
+            iter: None,
+            index: false,
+            future: false,
+            future_output: None,
+            io: false,
+        };
+        let parsed = BoilArg::parse_all(attr)?;
+        for (a, arg) in parsed.iter().enumerate() {
+            let flag = match arg.name.to_string().as_str() {
+                "conversions" => {
+                    args.conversions_vis = Some(arg.expect_visibility()?);
+                    continue;
+                }
+                "ops" => {
+                    for op in BoilArg::parse_all(arg.expect_list()?)? {
+                        op.expect_flag()?;
+                        if op.name.to_string() == "mixed" {
+                            args.mixed_ops = true;
+                        }
+                        else if OPS.iter().any(|o| op.name.to_string() == o.trait_name) {
+                            args.ops.push(op.name);
+                        }
+                        else {
+                            return Err(compile_error(&format!("Unknown operator `{}`.  Expected one of Add, Sub, Mul, Div, Rem, BitAnd, BitOr, BitXor, Shl, Shr, Neg, Not, or `mixed`", op.name), op.name.span()));
+                        }
+                    }
+                    continue;
+                }
+                "const_new" => {
+                    arg.expect_flag()?;
+                    args.const_new = Some(arg.name.clone());
+                    continue;
+                }
+                "iter" => {
+                    arg.expect_flag()?;
+                    args.iter = Some(arg.name.clone());
+                    continue;
+                }
+                "index" => {
+                    arg.expect_flag()?;
+                    args.index = true;
+                    continue;
+                }
+                "future" => {
+                    if let BoilArgValue::List(group) = &arg.value {
+                        for future_arg in BoilArg::parse_all(group.stream())? {
+                            match (future_arg.name.to_string().as_str(), &future_arg.value) {
+                                ("output", BoilArgValue::Assign(value)) => {
+                                    args.future_output = Some(value.to_string());
+                                }
+                                _ => {
+                                    return Err(compile_error(&format!("Unknown argument `{}`.  Expected `output = OutputType`", future_arg.name), future_arg.name.span()));
+                                }
+                            }
+                        }
+                    }
+                    else {
+                        arg.expect_flag()?;
+                    }
+                    args.future = true;
+                    continue;
+                }
+                "io" => {
+                    arg.expect_flag()?;
+                    args.io = true;
+                    continue;
+                }
+                "derive" => {
+                    args.derives.push(arg.expect_list()?);
+                    continue;
+                }
+                "preset" => {
+                    let path = arg.expect_path()?;
+                    if args.preset.is_none() {
+                        //later presets are expanded in turn by the preset we pass them to
+                        let mut rest = TokenStream::new();
+                        for (o, other) in parsed.iter().enumerate() {
+                            if o == a { continue }
+                            rest.extend(other.to_tokens());
+                            rest.extend([TokenTree::Punct(Punct::new(',', Spacing::Alone))]);
+                        }
+                        args.preset = Some((path, rest));
+                    }
+                    continue;
+                }
+                "scoped" => &mut args.scoped,
+                "no_as_ref" => &mut args.as_ref,
+                "no_borrow" => &mut args.borrow,
+                "no_from" => &mut args.from,
+                "no_projections" => &mut args.projections,
+                "no_deref" => &mut args.deref,
+                "no_deref_mut" => &mut args.deref_mut,
+                other => {
+                    return Err(compile_error(&format!("Unknown argument `{}`.  Expected one of `scoped`, `no_as_ref`, `no_borrow`, `no_from`, `no_projections`, `no_deref`, `no_deref_mut`, `conversions`, `derive`, `preset`, `ops`, `const_new`, `iter`, `index`, `future`, `io`", other), arg.name.span()));
+                }
+            };
+            arg.expect_flag()?;
+            //`scoped` turns a behavior on, the rest turn one off
+            *flag = arg.name.to_string() == "scoped";
+        }
+        if args.scoped {
+            args.deref = false;
+        }
+        //DerefMut requires Deref
+        if !args.deref {
+            args.deref_mut = false;
+        }
+        Ok(args)
+    }
+    /**
+    If a preset was requested, hands `item` to the preset's macro, which invokes `macro_name` again with the preset's arguments.
+
+    Returns `None` if there is no preset to expand.
+    */
+    fn expand_preset(&self, macro_name: &str, item: TokenStream) -> Option<TokenStream> {
+        let (path, rest) = self.preset.as_ref()?;
+        let mut code = path.clone();
+        code.extend([
+            TokenTree::Punct(Punct::new('!', Spacing::Alone)),
+        ]);
+        let mut body: TokenStream = TokenTree::Ident(Ident::new(macro_name, Span::call_site())).into();
+        body.extend([TokenTree::Group(Group::new(Delimiter::Bracket, rest.clone()))]);
+        body.extend(item);
+        code.extend([TokenTree::Group(Group::new(Delimiter::Brace, body))]);
+        Some(code)
+    }
+    ///`#[derive(...)]` attributes requested with `derive(...)`
+    fn derive_attributes(&self) -> TokenStream {
+        let mut code = TokenStream::new();
+        for derive in &self.derives {
+            let mut attr: TokenStream = TokenTree::Ident(Ident::new("derive", Span::call_site())).into();
+            attr.extend([TokenTree::Group(Group::new(Parenthesis, derive.clone()))]);
+            code.extend([
+                TokenTree::Punct(Punct::new('#', Spacing::Alone)),
+                TokenTree::Group(Group::new(Delimiter::Bracket, attr)),
+            ]);
         }
+        code
     }
 }
+
 struct BoilParse {
     ///The name of our wrapping type
     name: String,
@@ -301,51 +744,57 @@ struct BoilParse {
     vis: String,
     ///`where A: B` etc.
     where_clause: String,
+    ///The bracketed part of each attribute on the struct, like `[derive(Debug)]`
+    attributes: Vec<Group>,
 }
 impl BoilParse {
-    fn new(item: TokenStream) -> Self {
-        #[derive(PartialEq)]
-        enum State {
-            Initial,
-            Struct,
-        }
-        let mut state = State::Initial;
+    fn new(item: TokenStream) -> Result<Self, TokenStream> {
         let mut help_item = None;
+        let mut attributes = Vec::new();
         let mut item_iter = item.into_iter();
         for item in &mut item_iter {
             match item {
+                TokenTree::Group(g) if g.delimiter() == Delimiter::Bracket => {
+                    attributes.push(g.clone());
+                    help_item = Some(TokenTree::Group(g));
+                }
                 TokenTree::Ident(i) if i.to_string() == "struct" => {
-                    state = State::Struct;
                     help_item = Some(TokenTree::Ident(i));
                     break;
                 }
+                TokenTree::Ident(i) if i.to_string() == "enum" || i.to_string() == "union" => {
+                    return Err(compile_error(&format!("boil only supports tuple structs, not `{}`", i), i.span()));
+                }
                 other => {
                     help_item = Some(other);
                 }
             }
         }
-        let mut help_item = help_item.expect("#[boil] appears to have no content!");
-        assert!(state == State::Struct, "Expected a `struct` keyword before {}",help_item);
+        let mut help_item = match help_item {
+            Some(TokenTree::Ident(i)) if i.to_string() == "struct" => TokenTree::Ident(i),
+            Some(other) => {
+                return Err(compile_error("boil only supports tuple structs; expected a `struct` keyword", other.span()));
+            }
+            None => {
+                return Err(compile_error("boil appears to have no content; expected a tuple struct", Span::call_site()));
+            }
+        };
         //I think the next item should be an identifier of the type?
         let ident_item_in = item_iter.next();
-        let ident;
-        match ident_item_in {
+        let ident = match ident_item_in {
             None => {
-                panic!("Expected an identifier after {}",help_item);
-            }
-            Some(TokenTree::Ident(i)) => {
-                ident = i.to_string();
-
+                return Err(compile_error("Expected the name of the struct", help_item.span()));
             }
+            Some(TokenTree::Ident(i)) => i.to_string(),
             Some(other) => {
-                panic!("Expected an identifier instead of {} after {}",other, help_item)
+                return Err(compile_error(&format!("Expected the name of the struct instead of `{}`", other), other.span()));
             }
-        }
+        };
         //Either parenthesis or a generic argument
         let generics_maybe = item_iter.next();
 
 
-        let r = parse_generics_complex(generics_maybe.clone(), &mut item_iter, help_item);
+        let r = parse_generics_complex(generics_maybe.clone(), &mut item_iter, help_item)?;
         let wrap_generics_complex = r.0;
         let wrap_generics_simple = r.1;
         help_item = r.2;
@@ -353,7 +802,7 @@ impl BoilParse {
         //If we had no generics, parse generics_maybe.
         //If we had generics, pull the next item.
         let body_head = if wrap_generics_complex.is_empty() { generics_maybe } else { item_iter.next() };
-        let p = parse_body(body_head,help_item);
+        let p = parse_body(body_head,help_item)?;
         let path = p.0;
         let vis = p.1;
         let imp_generics = p.2;
@@ -363,7 +812,7 @@ impl BoilParse {
         //try to parse a where clause
         match item_iter.next() {
             None => {
-                panic!("Expected `;` near {}",help_item);
+                return Err(compile_error("Expected `;` after the tuple struct", help_item.span()));
             }
             Some(TokenTree::Ident(i)) if i.to_string() == "where" => {
                 where_clause += "where ";
@@ -372,7 +821,7 @@ impl BoilParse {
                     let item = item_iter.next();
                     match item {
                         None => {
-                            panic!("Expected end of where clause near {}",help_item);
+                            return Err(compile_error("Expected `;` to end the where clause", help_item.span()));
                         }
                         Some(TokenTree::Punct(p)) if p.to_string() == ";" => {
                             break;
@@ -388,10 +837,10 @@ impl BoilParse {
                 //ok to leave I guess?
             }
             Some(other) => {
-                todo!("{}",other);
+                return Err(compile_error(&format!("Unexpected `{}` after the tuple struct; expected `where` or `;`", other), other.span()));
             }
         }
-        Self {
+        Ok(Self {
             name: ident,
             imp: path,
             wrap_generics_simple,
@@ -399,12 +848,89 @@ impl BoilParse {
             imp_generics,
             vis: vis,
             where_clause,
+            attributes,
+        })
+    }
+    /**
+    The wrapper's generic parameters with an additional `param`, like `'a` or `T`, for impls that need their own.
+
+    Lifetimes go first and other parameters go last, as Rust requires.
+    */
+    fn generics_with(&self, param: &str) -> String {
+        let generics = self.wrap_generics_complex.trim();
+        //only the outer brackets, since the last bound may have generic arguments of its own, like `T: Into<Vec<u8>>`
+        let inner = generics.strip_prefix('<').and_then(|g| g.strip_suffix('>')).unwrap_or(generics).trim().trim_end_matches(',');
+        if inner.is_empty() {
+            format!("<{param}>")
+        }
+        else if param.starts_with('\'') {
+            format!("<{param}, {inner}>")
+        }
+        else {
+            format!("<{inner}, {param}>")
+        }
+    }
+    ///The where clause with an additional `bound`.
+    fn where_clause_with(&self, bound: &str) -> String {
+        if self.where_clause.is_empty() {
+            format!("where {bound}")
+        }
+        else {
+            format!("{}, {bound}", self.where_clause.trim_end().trim_end_matches(','))
+        }
+    }
+    /**
+    The where clause with an additional `bound` that may not hold, like `Payload: std::io::Read`.
+
+    Rust rejects an impl whose bound has no generic parameters and doesn't hold, so the bound is made higher-ranked to
+    defer the check to each use.  Then a wrapper without generics over a payload that implements only some of the
+    forwarded traits, like a `File` or a `PathBuf`, still compiles.
+    */
+    fn where_clause_with_optional(&self, bound: &str) -> String {
+        self.where_clause_with(&format!("for<'boil_hr> {bound}"))
+    }
+    /**
+    Finds a derive's helper attribute, like `#[debug(prefix)]`, returning the contents of each parenthesis (`prefix`).
+
+    The attribute may appear more than once.  `#[debug]` without parenthesis has empty contents.
+    */
+    fn helper_attributes(&self, name: &str) -> Result<Vec<TokenStream>, TokenStream> {
+        let mut contents = Vec::new();
+        for attribute in &self.attributes {
+            let mut iter = attribute.stream().into_iter();
+            match iter.next() {
+                Some(TokenTree::Ident(i)) if i.to_string() == name => {
+                    match iter.next() {
+                        None => contents.push(TokenStream::new()),
+                        Some(TokenTree::Group(g)) if g.delimiter() == Parenthesis => {
+                            contents.push(g.stream());
+                        }
+                        Some(other) => {
+                            return Err(compile_error(&format!("Expected arguments like `#[{}(...)]`", name), other.span()));
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+        Ok(contents)
+    }
+    /**
+    Parses the arguments of a derive's helper attribute, like the `prefix` in `#[debug(prefix)]`.
+
+    If the attribute appears more than once, the arguments are combined.  If it doesn't appear, there are no arguments.
+    */
+    fn helper_arguments(&self, name: &str) -> Result<Vec<BoilArg>, TokenStream> {
+        let mut args = Vec::new();
+        for contents in self.helper_attributes(name)? {
+            args.extend(BoilArg::parse_all(contents)?);
         }
+        Ok(args)
     }
-    pub fn implement(&self, scoped: bool) -> String {
+    pub fn implement(&self, args: &BoilArgs) -> String {
         let identifier = &self.name;
         let imp = &self.imp;
-        let vis = &self.vis;
+        let vis = args.conversions_vis.as_ref().unwrap_or(&self.vis);
         let wrap_generics_simple = &self.wrap_generics_simple;
         let wrap_generics_complex = &self.wrap_generics_complex;
         let imp_generics = &self.imp_generics;
@@ -416,7 +942,9 @@ impl BoilParse {
         //     chars.next().unwrap();
         //     ",".to_owned() + chars.as_str()
         // };
-        let mut base_impl = format!("
+        let mut base_impl = String::new();
+        if args.as_ref {
+            base_impl += &format!("
         //asref
         impl {wrap_generics_complex} AsRef<{identifier}{wrap_generics_simple}> for {imp}{imp_generics} {where_clause} {{
              fn as_ref(&self) -> &{identifier}{wrap_generics_simple} {{
@@ -443,6 +971,10 @@ impl BoilParse {
                 &mut self.0
              }}
         }}
+        ");
+        }
+        if args.borrow {
+            base_impl += &format!("
         //borrow
         impl {wrap_generics_complex} std::borrow::Borrow<{identifier}{wrap_generics_simple}> for {imp}{imp_generics} {where_clause} {{
              fn borrow(&self) -> &{identifier}{wrap_generics_simple} {{
@@ -466,6 +998,10 @@ impl BoilParse {
                 &mut self.0
              }}
         }}
+        ");
+        }
+        if args.from {
+            base_impl += &format!("
         //from/into
         impl {wrap_generics_complex} From<{imp}{imp_generics}> for {identifier} {wrap_generics_simple} {where_clause} {{
             fn from(t: {imp}{imp_generics}) -> Self {{
@@ -477,6 +1013,10 @@ impl BoilParse {
                 t.0
             }}
         }}
+        ");
+        }
+        if args.projections {
+            base_impl += &format!("
         //projections.  Box:
         impl {wrap_generics_complex} From<Box<{imp}{imp_generics}>> for Box<{identifier} {wrap_generics_simple}> {where_clause} {{
             fn from(t: Box<{imp}{imp_generics}>) -> Self {{
@@ -575,6 +1115,123 @@ impl BoilParse {
             }}
         }}
 
+        //slice projections
+        impl {wrap_generics_complex} {identifier}{wrap_generics_simple} {where_clause} {{
+            /**
+            Converts from a slice of the underlying type to a slice of the wrapper.
+
+            This is a zero-cost abstraction. */
+            {vis} fn from_slice<'boil_s>(slice: &'boil_s [{imp}{imp_generics}]) -> &'boil_s [{identifier}{wrap_generics_simple}] {{
+                //safe because we're layout-compatible
+                unsafe {{ std::slice::from_raw_parts(slice.as_ptr() as *const {identifier}{wrap_generics_simple}, slice.len()) }}
+            }}
+            /**
+            Converts from a mutable slice of the underlying type to a mutable slice of the wrapper.
+
+            This is a zero-cost abstraction. */
+            {vis} fn from_slice_mut<'boil_s>(slice: &'boil_s mut [{imp}{imp_generics}]) -> &'boil_s mut [{identifier}{wrap_generics_simple}] {{
+                //safe because we're layout-compatible
+                unsafe {{ std::slice::from_raw_parts_mut(slice.as_mut_ptr() as *mut {identifier}{wrap_generics_simple}, slice.len()) }}
+            }}
+            /**
+            Converts from a slice of the wrapper to a slice of the underlying type.
+
+            This is a zero-cost abstraction. */
+            {vis} fn as_inner_slice<'boil_s>(slice: &'boil_s [Self]) -> &'boil_s [{imp}{imp_generics}] {{
+                //safe because we're layout-compatible
+                unsafe {{ std::slice::from_raw_parts(slice.as_ptr() as *const {imp}{imp_generics}, slice.len()) }}
+            }}
+            /**
+            Converts from a mutable slice of the wrapper to a mutable slice of the underlying type.
+
+            This is a zero-cost abstraction. */
+            {vis} fn as_inner_slice_mut<'boil_s>(slice: &'boil_s mut [Self]) -> &'boil_s mut [{imp}{imp_generics}] {{
+                //safe because we're layout-compatible
+                unsafe {{ std::slice::from_raw_parts_mut(slice.as_mut_ptr() as *mut {imp}{imp_generics}, slice.len()) }}
+            }}
+        }}
+
+        //owned collection projections
+        impl {wrap_generics_complex} {identifier}{wrap_generics_simple} {where_clause} {{
+            /**
+            Converts from a [Vec] of the underlying type to a [Vec] of the wrapper.
+
+            This is a zero-cost abstraction; the allocation is reused. */
+            {vis} fn from_vec(vec: Vec<{imp}{imp_generics}>) -> Vec<{identifier}{wrap_generics_simple}> {{
+                let mut vec = std::mem::ManuallyDrop::new(vec);
+                //safe because we're layout-compatible, so the allocation's layout is unchanged
+                unsafe {{ Vec::from_raw_parts(vec.as_mut_ptr() as *mut {identifier}{wrap_generics_simple}, vec.len(), vec.capacity()) }}
+            }}
+            /**
+            Converts from a [Vec] of the wrapper to a [Vec] of the underlying type.
+
+            This is a zero-cost abstraction; the allocation is reused. */
+            {vis} fn into_inner_vec(vec: Vec<Self>) -> Vec<{imp}{imp_generics}> {{
+                let mut vec = std::mem::ManuallyDrop::new(vec);
+                //safe because we're layout-compatible, so the allocation's layout is unchanged
+                unsafe {{ Vec::from_raw_parts(vec.as_mut_ptr() as *mut {imp}{imp_generics}, vec.len(), vec.capacity()) }}
+            }}
+            /**
+            Converts from a boxed slice of the underlying type to a boxed slice of the wrapper.
+
+            This is a zero-cost abstraction. */
+            {vis} fn from_boxed_slice(slice: Box<[{imp}{imp_generics}]>) -> Box<[{identifier}{wrap_generics_simple}]> {{
+                //the cast keeps the slice's length
+                let f = Box::into_raw(slice) as *mut [{identifier}{wrap_generics_simple}];
+                //safe because we're layout-compatible
+                unsafe {{ Box::from_raw(f) }}
+            }}
+            /**
+            Converts from a boxed slice of the wrapper to a boxed slice of the underlying type.
+
+            This is a zero-cost abstraction. */
+            {vis} fn into_inner_boxed_slice(slice: Box<[Self]>) -> Box<[{imp}{imp_generics}]> {{
+                let f = Box::into_raw(slice) as *mut [{imp}{imp_generics}];
+                //safe because we're layout-compatible
+                unsafe {{ Box::from_raw(f) }}
+            }}
+            /**
+            Converts from an [std::sync::Arc] slice of the underlying type to an [std::sync::Arc] slice of the wrapper.
+
+            This is a zero-cost abstraction. */
+            {vis} fn from_arc_slice(slice: std::sync::Arc<[{imp}{imp_generics}]>) -> std::sync::Arc<[{identifier}{wrap_generics_simple}]> {{
+                let f = std::sync::Arc::into_raw(slice) as *const [{identifier}{wrap_generics_simple}];
+                //safe because we're layout-compatible
+                unsafe {{ std::sync::Arc::from_raw(f) }}
+            }}
+            /**
+            Converts from an [std::sync::Arc] slice of the wrapper to an [std::sync::Arc] slice of the underlying type.
+
+            This is a zero-cost abstraction. */
+            {vis} fn into_inner_arc_slice(slice: std::sync::Arc<[Self]>) -> std::sync::Arc<[{imp}{imp_generics}]> {{
+                let f = std::sync::Arc::into_raw(slice) as *const [{imp}{imp_generics}];
+                //safe because we're layout-compatible
+                unsafe {{ std::sync::Arc::from_raw(f) }}
+            }}
+            /**
+            Converts from an [std::rc::Rc] slice of the underlying type to an [std::rc::Rc] slice of the wrapper.
+
+            This is a zero-cost abstraction. */
+            {vis} fn from_rc_slice(slice: std::rc::Rc<[{imp}{imp_generics}]>) -> std::rc::Rc<[{identifier}{wrap_generics_simple}]> {{
+                let f = std::rc::Rc::into_raw(slice) as *const [{identifier}{wrap_generics_simple}];
+                //safe because we're layout-compatible
+                unsafe {{ std::rc::Rc::from_raw(f) }}
+            }}
+            /**
+            Converts from an [std::rc::Rc] slice of the wrapper to an [std::rc::Rc] slice of the underlying type.
+
+            This is a zero-cost abstraction. */
+            {vis} fn into_inner_rc_slice(slice: std::rc::Rc<[Self]>) -> std::rc::Rc<[{imp}{imp_generics}]> {{
+                let f = std::rc::Rc::into_raw(slice) as *const [{imp}{imp_generics}];
+                //safe because we're layout-compatible
+                unsafe {{ std::rc::Rc::from_raw(f) }}
+            }}
+        }}
+
+        ");
+        }
+        if args.from {
+            base_impl += &format!("
         //Result projections
         impl {wrap_generics_complex} {identifier} {wrap_generics_simple} {where_clause} {{
             /**
@@ -591,40 +1248,697 @@ impl BoilParse {
             {vis} fn into_result<E: Into<EUnwrapped>,EUnwrapped>(r: Result<Self,E>) -> Result<{imp}{imp_generics},EUnwrapped> {{
                 r.map(|t| t.into()).map_err(|e| e.into())
             }}
+            /**
+            Converts from Result with a reference to the underlying type, into Result of a reference to the wrapped type.
+
+            If necessary, also converts Error between any types that can be converted with [Into], including between wrapped or underlying types.*/
+            {vis} fn from_result_ref<'boil_r,E: Into<ErrWrapped>,ErrWrapped>(r: Result<&'boil_r {imp}{imp_generics},E>) -> Result<&'boil_r Self,ErrWrapped> {{
+                //safe because we're layout-compatible
+                r.map(|t| unsafe {{ &*(t as *const {imp}{imp_generics} as *const Self) }}).map_err(|e| e.into())
+            }}
+            /**
+            Converts from Result with a reference to the wrapped type, into Result of a reference to the underlying type.
+
+            If necessary, also converts Error between any types that can be converted with [Into], including between wrapped or underlying types.*/
+            {vis} fn into_result_ref<'boil_r,E: Into<EUnwrapped>,EUnwrapped>(r: Result<&'boil_r Self,E>) -> Result<&'boil_r {imp}{imp_generics},EUnwrapped> {{
+                r.map(|t| &t.0).map_err(|e| e.into())
+            }}
+            /**
+            Converts from Result with a mutable reference to the underlying type, into Result of a mutable reference to the wrapped type.
+
+            If necessary, also converts Error between any types that can be converted with [Into], including between wrapped or underlying types.*/
+            {vis} fn from_result_mut<'boil_r,E: Into<ErrWrapped>,ErrWrapped>(r: Result<&'boil_r mut {imp}{imp_generics},E>) -> Result<&'boil_r mut Self,ErrWrapped> {{
+                //safe because we're layout-compatible
+                r.map(|t| unsafe {{ &mut *(t as *mut {imp}{imp_generics} as *mut Self) }}).map_err(|e| e.into())
+            }}
+            /**
+            Converts from Result with a mutable reference to the wrapped type, into Result of a mutable reference to the underlying type.
+
+            If necessary, also converts Error between any types that can be converted with [Into], including between wrapped or underlying types.*/
+            {vis} fn into_result_mut<'boil_r,E: Into<EUnwrapped>,EUnwrapped>(r: Result<&'boil_r mut Self,E>) -> Result<&'boil_r mut {imp}{imp_generics},EUnwrapped> {{
+                r.map(|t| &mut t.0).map_err(|e| e.into())
+            }}
         }}
 
         ");
-        if !scoped {
-            base_impl += &format!(
-                "        //deref and derefmut
-        impl {wrap_generics_complex} std::ops::Deref for {identifier}{wrap_generics_simple} {where_clause} {{
-            type Target = {imp}{imp_generics};
-            fn deref(&self) -> &Self::Target {{
-                &self.0
+        }
+        if args.projections {
+            base_impl += &self.implement_pin_pointers(vis);
+            base_impl += &self.implement_weak(vis);
+            base_impl += &self.implement_pointers(vis);
+            base_impl += &self.implement_cells(vis);
+            base_impl += &format!("
+        //Option projections
+        impl {wrap_generics_complex} {identifier}{wrap_generics_simple} {where_clause} {{
+            /**
+            Converts from Option of underlying type, into Option of wrapped type.
+
+            This is a zero-cost abstraction. */
+            {vis} fn from_option(o: Option<{imp}{imp_generics}>) -> Option<Self> {{
+                o.map(Self)
             }}
-        }}
-        impl {wrap_generics_complex} std::ops::DerefMut for {identifier} {wrap_generics_simple} {where_clause} {{
-            fn deref_mut(&mut self) -> &mut Self::Target {{
-                &mut self.0
+            /**
+            Converts from Option of wrapped type, into Option of underlying type.
+
+            This is a zero-cost abstraction. */
+            {vis} fn into_option(o: Option<Self>) -> Option<{imp}{imp_generics}> {{
+                o.map(|t| t.0)
             }}
-        }}"
-            );
-        }
-        base_impl
-    }
-    fn implement_unsized(&self,scoped: bool) -> String {
-        let wrap_generics_complex = &self.wrap_generics_complex;
-        let identifier = &self.name;
-        let where_clause = &self.where_clause;
-        let imp = &self.imp;
-        let imp_generics = &self.imp_generics;
-        let wrap_generics_simple = &self.wrap_generics_simple;
-        let vis = &self.vis;
-        let mut impl_text = format!("
-        impl {wrap_generics_complex} AsRef<{identifier}{wrap_generics_simple}> for {imp}{imp_generics} {where_clause} {{
-             fn as_ref(&self) -> &{identifier}{wrap_generics_simple} {{
-                //safe because identifier is layout-compatible with `dyn Trait`
-                unsafe {{ std::mem::transmute(self) }}
+            /**
+            Converts from Option of a reference to the underlying type, into Option of a reference to the wrapped type.
+
+            This is a zero-cost abstraction. */
+            {vis} fn from_option_ref<'boil_o>(o: Option<&'boil_o {imp}{imp_generics}>) -> Option<&'boil_o Self> {{
+                //safe because we're layout-compatible
+                o.map(|t| unsafe {{ &*(t as *const {imp}{imp_generics} as *const Self) }})
+            }}
+            /**
+            Converts from Option of a reference to the wrapped type, into Option of a reference to the underlying type.
+
+            This is a zero-cost abstraction. */
+            {vis} fn into_option_ref<'boil_o>(o: Option<&'boil_o Self>) -> Option<&'boil_o {imp}{imp_generics}> {{
+                o.map(|t| &t.0)
+            }}
+            /**
+            Converts from Option of a mutable reference to the underlying type, into Option of a mutable reference to the wrapped type.
+
+            This is a zero-cost abstraction. */
+            {vis} fn from_option_mut<'boil_o>(o: Option<&'boil_o mut {imp}{imp_generics}>) -> Option<&'boil_o mut Self> {{
+                //safe because we're layout-compatible
+                o.map(|t| unsafe {{ &mut *(t as *mut {imp}{imp_generics} as *mut Self) }})
+            }}
+            /**
+            Converts from Option of a mutable reference to the wrapped type, into Option of a mutable reference to the underlying type.
+
+            This is a zero-cost abstraction. */
+            {vis} fn into_option_mut<'boil_o>(o: Option<&'boil_o mut Self>) -> Option<&'boil_o mut {imp}{imp_generics}> {{
+                o.map(|t| &mut t.0)
+            }}
+            /**
+            Converts from Option of a [Box] of underlying type, into Option of a [Box] of wrapped type.
+
+            This is a zero-cost abstraction. */
+            {vis} fn from_option_box(o: Option<Box<{imp}{imp_generics}>>) -> Option<Box<Self>> {{
+                //safe because we're layout-compatible
+                o.map(|t| unsafe {{ Box::from_raw(Box::into_raw(t) as *mut Self) }})
+            }}
+            /**
+            Converts from Option of a [Box] of wrapped type, into Option of a [Box] of underlying type.
+
+            This is a zero-cost abstraction. */
+            {vis} fn into_option_box(o: Option<Box<Self>>) -> Option<Box<{imp}{imp_generics}>> {{
+                //safe because we're layout-compatible
+                o.map(|t| unsafe {{ Box::from_raw(Box::into_raw(t) as *mut {imp}{imp_generics}) }})
+            }}
+        }}
+
+        ");
+        }
+        if args.deref {
+            base_impl += &format!(
+                "        //deref and derefmut
+        impl {wrap_generics_complex} std::ops::Deref for {identifier}{wrap_generics_simple} {where_clause} {{
+            type Target = {imp}{imp_generics};
+            fn deref(&self) -> &Self::Target {{
+                &self.0
+            }}
+        }}"
+            );
+        }
+        if args.deref_mut {
+            base_impl += &format!(
+                "
+        impl {wrap_generics_complex} std::ops::DerefMut for {identifier} {wrap_generics_simple} {where_clause} {{
+            fn deref_mut(&mut self) -> &mut Self::Target {{
+                &mut self.0
+            }}
+        }}"
+            );
+        }
+        if args.const_new.is_some() {
+            let field_vis = &self.vis;
+            base_impl += &format!("
+        //const constructors
+        impl {wrap_generics_complex} {identifier}{wrap_generics_simple} {where_clause} {{
+            /**
+            Wraps the underlying type.
+
+            Unlike [From], this can be used in `const` and `static` items.*/
+            {field_vis} const fn new(inner: {imp}{imp_generics}) -> Self {{
+                Self(inner)
+            }}
+            /**
+            Unwraps the underlying type.
+
+            Unlike [Into], this can be used in `const` contexts.*/
+            {field_vis} const fn into_inner(self) -> {imp}{imp_generics} {{
+                //moving out of `self.0` would need to drop `self`, which const fns can't do for generic payloads
+                let this = std::mem::ManuallyDrop::new(self);
+                //safe because we're layout-compatible, and `this` is never dropped
+                unsafe {{ std::ptr::read(&this as *const std::mem::ManuallyDrop<Self> as *const {imp}{imp_generics}) }}
+            }}
+        }}
+            ");
+        }
+        if args.iter.is_some() {
+            base_impl += &self.implement_iter();
+        }
+        if args.index {
+            base_impl += &self.implement_index();
+        }
+        if args.future {
+            base_impl += &self.implement_future(args);
+        }
+        if args.io {
+            base_impl += &self.implement_io(&format!("{}{}", self.imp, self.imp_generics));
+        }
+        base_impl += &self.implement_ops(args);
+        base_impl
+    }
+    /**
+    Projects owning pinned pointers, `Pin<Box<_>>`, `Pin<Arc<_>>` and `Pin<Rc<_>>`, through the unpinned projections.
+
+    Shared by sized and unsized wrappers, since the unpinned projections already handle both.
+    */
+    fn implement_pin_pointers(&self, vis: &str) -> String {
+        let identifier = &self.name;
+        let imp = &self.imp;
+        let wrap_generics_simple = &self.wrap_generics_simple;
+        let wrap_generics_complex = &self.wrap_generics_complex;
+        let imp_generics = &self.imp_generics;
+        let where_clause = &self.where_clause;
+        format!("
+        //owning Pin projections
+        impl {wrap_generics_complex} From<core::pin::Pin<Box<{imp}{imp_generics}>>> for core::pin::Pin<Box<{identifier}{wrap_generics_simple}>> {where_clause} {{
+            fn from(t: core::pin::Pin<Box<{imp}{imp_generics}>>) -> Self {{
+                //safe because the payload is pinned exactly when the wrapper is, as in the Pin<&mut> projections
+                unsafe {{ core::pin::Pin::new_unchecked(Box::from(core::pin::Pin::into_inner_unchecked(t))) }}
+            }}
+        }}
+        impl {wrap_generics_complex} From<core::pin::Pin<Box<{identifier}{wrap_generics_simple}>>> for core::pin::Pin<Box<{imp}{imp_generics}>> {where_clause} {{
+            fn from(t: core::pin::Pin<Box<{identifier}{wrap_generics_simple}>>) -> Self {{
+                //safe because the payload is pinned exactly when the wrapper is, as in the Pin<&mut> projections
+                unsafe {{ core::pin::Pin::new_unchecked(Box::from(core::pin::Pin::into_inner_unchecked(t))) }}
+            }}
+        }}
+        impl {wrap_generics_complex} {identifier}{wrap_generics_simple} {where_clause} {{
+            /**
+            Converts from a pinned [std::sync::Arc] of underlying type to a pinned [std::sync::Arc] of the wrapper.
+
+            This is a zero-cost abstraction. */
+            {vis} fn from_pin_arc(arc: core::pin::Pin<std::sync::Arc<{imp}{imp_generics}>>) -> core::pin::Pin<std::sync::Arc<{identifier}{wrap_generics_simple}>> {{
+                //safe because the payload is pinned exactly when the wrapper is, as in the Pin<&mut> projections
+                unsafe {{ core::pin::Pin::new_unchecked(Self::from_arc(core::pin::Pin::into_inner_unchecked(arc))) }}
+            }}
+            /**
+            Converts from a pinned [std::sync::Arc] of wrapper type to a pinned [std::sync::Arc] of the underlying type.
+
+            This is a zero-cost abstraction. */
+            {vis} fn to_pin_arc(self: core::pin::Pin<std::sync::Arc<Self>>) -> core::pin::Pin<std::sync::Arc<{imp}{imp_generics}>> {{
+                //safe because the payload is pinned exactly when the wrapper is, as in the Pin<&mut> projections
+                unsafe {{ core::pin::Pin::new_unchecked(Self::to_arc(core::pin::Pin::into_inner_unchecked(self))) }}
+            }}
+            /**
+            Converts from a pinned [std::rc::Rc] of underlying type to a pinned [std::rc::Rc] of the wrapper.
+
+            This is a zero-cost abstraction. */
+            {vis} fn from_pin_rc(rc: core::pin::Pin<std::rc::Rc<{imp}{imp_generics}>>) -> core::pin::Pin<std::rc::Rc<{identifier}{wrap_generics_simple}>> {{
+                //safe because the payload is pinned exactly when the wrapper is, as in the Pin<&mut> projections
+                unsafe {{ core::pin::Pin::new_unchecked(Self::from_rc(core::pin::Pin::into_inner_unchecked(rc))) }}
+            }}
+            /**
+            Converts from a pinned [std::rc::Rc] of wrapper type to a pinned [std::rc::Rc] of the underlying type.
+
+            This is a zero-cost abstraction. */
+            {vis} fn to_pin_rc(self: core::pin::Pin<std::rc::Rc<Self>>) -> core::pin::Pin<std::rc::Rc<{imp}{imp_generics}>> {{
+                //safe because the payload is pinned exactly when the wrapper is, as in the Pin<&mut> projections
+                unsafe {{ core::pin::Pin::new_unchecked(Self::to_rc(core::pin::Pin::into_inner_unchecked(self))) }}
+            }}
+        }}
+        ")
+    }
+    /**
+    Casts raw pointers and `NonNull` between the payload and the wrapper.
+
+    Shared by sized and unsized wrappers.  Every cast ends in `*mut`, as in `to_arc`, so it keeps an unsized pointer's
+    metadata rather than being mistaken for an unsizing coercion.
+    */
+    fn implement_pointers(&self, vis: &str) -> String {
+        let identifier = &self.name;
+        let imp = &self.imp;
+        let wrap_generics_simple = &self.wrap_generics_simple;
+        let wrap_generics_complex = &self.wrap_generics_complex;
+        let imp_generics = &self.imp_generics;
+        let where_clause = &self.where_clause;
+        format!("
+        //pointer projections
+        impl {wrap_generics_complex} {identifier}{wrap_generics_simple} {where_clause} {{
+            /**
+            Casts a pointer to the underlying type to a pointer to the wrapper.
+
+            The pointer is not dereferenced, so this is safe; dereferencing the result is as safe as dereferencing `ptr`. */
+            {vis} fn from_ptr(ptr: *const {imp}{imp_generics}) -> *const Self {{
+                ptr as *mut Self as *const Self
+            }}
+            /**
+            Casts a mutable pointer to the underlying type to a mutable pointer to the wrapper.
+
+            The pointer is not dereferenced, so this is safe; dereferencing the result is as safe as dereferencing `ptr`. */
+            {vis} fn from_ptr_mut(ptr: *mut {imp}{imp_generics}) -> *mut Self {{
+                ptr as *const {imp}{imp_generics} as *mut Self
+            }}
+            /**
+            Casts a pointer to the wrapper to a pointer to the underlying type.
+
+            The pointer is not dereferenced, so this is safe; dereferencing the result is as safe as dereferencing `ptr`. */
+            {vis} fn as_inner_ptr(ptr: *const Self) -> *const {imp}{imp_generics} {{
+                ptr as *mut {imp}{imp_generics} as *const {imp}{imp_generics}
+            }}
+            /**
+            Casts a mutable pointer to the wrapper to a mutable pointer to the underlying type.
+
+            The pointer is not dereferenced, so this is safe; dereferencing the result is as safe as dereferencing `ptr`. */
+            {vis} fn as_inner_ptr_mut(ptr: *mut Self) -> *mut {imp}{imp_generics} {{
+                ptr as *const Self as *mut {imp}{imp_generics}
+            }}
+            /**
+            Casts a [std::ptr::NonNull] of the underlying type to a [std::ptr::NonNull] of the wrapper. */
+            {vis} fn from_non_null(ptr: std::ptr::NonNull<{imp}{imp_generics}>) -> std::ptr::NonNull<Self> {{
+                //safe because the cast keeps the address, which is non-null
+                unsafe {{ std::ptr::NonNull::new_unchecked(Self::from_ptr_mut(ptr.as_ptr())) }}
+            }}
+            /**
+            Casts a [std::ptr::NonNull] of the wrapper to a [std::ptr::NonNull] of the underlying type. */
+            {vis} fn as_inner_non_null(ptr: std::ptr::NonNull<Self>) -> std::ptr::NonNull<{imp}{imp_generics}> {{
+                //safe because the cast keeps the address, which is non-null
+                unsafe {{ std::ptr::NonNull::new_unchecked(Self::as_inner_ptr_mut(ptr.as_ptr())) }}
+            }}
+        }}
+        ")
+    }
+    /**
+    Projects through the std cells that are layout-compatible with their contents:
+    `Cell`, `UnsafeCell`, `ManuallyDrop` and `MaybeUninit`.
+    */
+    fn implement_cells(&self, vis: &str) -> String {
+        let identifier = &self.name;
+        let imp = &self.imp;
+        let wrap_generics_simple = &self.wrap_generics_simple;
+        let wrap_generics_complex = &self.wrap_generics_complex;
+        let imp_generics = &self.imp_generics;
+        let where_clause = &self.where_clause;
+        format!("
+        //cell projections
+        impl {wrap_generics_complex} {identifier}{wrap_generics_simple} {where_clause} {{
+            /**
+            Converts from a [std::cell::Cell] of the underlying type to a [std::cell::Cell] of the wrapper.
+
+            This is a zero-cost abstraction. */
+            {vis} fn from_cell<'boil_c>(cell: &'boil_c std::cell::Cell<{imp}{imp_generics}>) -> &'boil_c std::cell::Cell<{identifier}{wrap_generics_simple}> {{
+                //safe because we're layout-compatible, and Cell is repr(transparent)
+                unsafe {{ &*(cell as *const std::cell::Cell<{imp}{imp_generics}> as *const std::cell::Cell<{identifier}{wrap_generics_simple}>) }}
+            }}
+            /**
+            Converts from a [std::cell::Cell] of the wrapper to a [std::cell::Cell] of the underlying type.
+
+            This is a zero-cost abstraction. */
+            {vis} fn as_inner_cell<'boil_c>(cell: &'boil_c std::cell::Cell<Self>) -> &'boil_c std::cell::Cell<{imp}{imp_generics}> {{
+                //safe because we're layout-compatible, and Cell is repr(transparent)
+                unsafe {{ &*(cell as *const std::cell::Cell<Self> as *const std::cell::Cell<{imp}{imp_generics}>) }}
+            }}
+            /**
+            Converts from an [std::cell::UnsafeCell] of the underlying type to an [std::cell::UnsafeCell] of the wrapper.
+
+            This is a zero-cost abstraction. */
+            {vis} fn from_unsafe_cell<'boil_c>(cell: &'boil_c std::cell::UnsafeCell<{imp}{imp_generics}>) -> &'boil_c std::cell::UnsafeCell<{identifier}{wrap_generics_simple}> {{
+                //safe because we're layout-compatible, and UnsafeCell is repr(transparent)
+                unsafe {{ &*(cell as *const std::cell::UnsafeCell<{imp}{imp_generics}> as *const std::cell::UnsafeCell<{identifier}{wrap_generics_simple}>) }}
+            }}
+            /**
+            Converts from an [std::cell::UnsafeCell] of the wrapper to an [std::cell::UnsafeCell] of the underlying type.
+
+            This is a zero-cost abstraction. */
+            {vis} fn as_inner_unsafe_cell<'boil_c>(cell: &'boil_c std::cell::UnsafeCell<Self>) -> &'boil_c std::cell::UnsafeCell<{imp}{imp_generics}> {{
+                //safe because we're layout-compatible, and UnsafeCell is repr(transparent)
+                unsafe {{ &*(cell as *const std::cell::UnsafeCell<Self> as *const std::cell::UnsafeCell<{imp}{imp_generics}>) }}
+            }}
+            /**
+            Converts from a [std::mem::ManuallyDrop] of the underlying type to a [std::mem::ManuallyDrop] of the wrapper.
+
+            This is a zero-cost abstraction. */
+            {vis} fn from_manually_drop(value: std::mem::ManuallyDrop<{imp}{imp_generics}>) -> std::mem::ManuallyDrop<{identifier}{wrap_generics_simple}> {{
+                std::mem::ManuallyDrop::new(Self(std::mem::ManuallyDrop::into_inner(value)))
+            }}
+            /**
+            Converts from a [std::mem::ManuallyDrop] of the wrapper to a [std::mem::ManuallyDrop] of the underlying type.
+
+            This is a zero-cost abstraction. */
+            {vis} fn into_inner_manually_drop(value: std::mem::ManuallyDrop<Self>) -> std::mem::ManuallyDrop<{imp}{imp_generics}> {{
+                std::mem::ManuallyDrop::new(std::mem::ManuallyDrop::into_inner(value).0)
+            }}
+            /**
+            Converts from a [std::mem::MaybeUninit] of the underlying type to a [std::mem::MaybeUninit] of the wrapper.
+
+            This is a zero-cost abstraction. */
+            {vis} fn from_maybe_uninit(value: std::mem::MaybeUninit<{imp}{imp_generics}>) -> std::mem::MaybeUninit<{identifier}{wrap_generics_simple}> {{
+                //safe because we're layout-compatible, and MaybeUninit never drops its contents
+                unsafe {{ std::ptr::read(&value as *const std::mem::MaybeUninit<{imp}{imp_generics}> as *const std::mem::MaybeUninit<{identifier}{wrap_generics_simple}>) }}
+            }}
+            /**
+            Converts from a [std::mem::MaybeUninit] of the wrapper to a [std::mem::MaybeUninit] of the underlying type.
+
+            This is a zero-cost abstraction. */
+            {vis} fn into_inner_maybe_uninit(value: std::mem::MaybeUninit<Self>) -> std::mem::MaybeUninit<{imp}{imp_generics}> {{
+                //safe because we're layout-compatible, and MaybeUninit never drops its contents
+                unsafe {{ std::ptr::read(&value as *const std::mem::MaybeUninit<Self> as *const std::mem::MaybeUninit<{imp}{imp_generics}>) }}
+            }}
+            /**
+            Converts from a mutable [std::mem::MaybeUninit] of the underlying type to a mutable [std::mem::MaybeUninit] of the wrapper.
+
+            This is a zero-cost abstraction. */
+            {vis} fn from_maybe_uninit_mut<'boil_c>(value: &'boil_c mut std::mem::MaybeUninit<{imp}{imp_generics}>) -> &'boil_c mut std::mem::MaybeUninit<{identifier}{wrap_generics_simple}> {{
+                //safe because we're layout-compatible, and MaybeUninit is repr(transparent)
+                unsafe {{ &mut *(value as *mut std::mem::MaybeUninit<{imp}{imp_generics}> as *mut std::mem::MaybeUninit<{identifier}{wrap_generics_simple}>) }}
+            }}
+            /**
+            Converts from a mutable [std::mem::MaybeUninit] of the wrapper to a mutable [std::mem::MaybeUninit] of the underlying type.
+
+            This is a zero-cost abstraction. */
+            {vis} fn as_inner_maybe_uninit_mut<'boil_c>(value: &'boil_c mut std::mem::MaybeUninit<Self>) -> &'boil_c mut std::mem::MaybeUninit<{imp}{imp_generics}> {{
+                //safe because we're layout-compatible, and MaybeUninit is repr(transparent)
+                unsafe {{ &mut *(value as *mut std::mem::MaybeUninit<Self> as *mut std::mem::MaybeUninit<{imp}{imp_generics}>) }}
+            }}
+            /**
+            Converts from a slice of [std::mem::MaybeUninit] of the underlying type to a slice of [std::mem::MaybeUninit] of the wrapper.
+
+            This is a zero-cost abstraction. */
+            {vis} fn from_maybe_uninit_slice<'boil_c>(slice: &'boil_c [std::mem::MaybeUninit<{imp}{imp_generics}>]) -> &'boil_c [std::mem::MaybeUninit<{identifier}{wrap_generics_simple}>] {{
+                //safe because we're layout-compatible
+                unsafe {{ std::slice::from_raw_parts(slice.as_ptr() as *const std::mem::MaybeUninit<{identifier}{wrap_generics_simple}>, slice.len()) }}
+            }}
+            /**
+            Converts from a slice of [std::mem::MaybeUninit] of the wrapper to a slice of [std::mem::MaybeUninit] of the underlying type.
+
+            This is a zero-cost abstraction. */
+            {vis} fn as_inner_maybe_uninit_slice<'boil_c>(slice: &'boil_c [std::mem::MaybeUninit<Self>]) -> &'boil_c [std::mem::MaybeUninit<{imp}{imp_generics}>] {{
+                //safe because we're layout-compatible
+                unsafe {{ std::slice::from_raw_parts(slice.as_ptr() as *const std::mem::MaybeUninit<{imp}{imp_generics}>, slice.len()) }}
+            }}
+            /**
+            Converts from a mutable slice of [std::mem::MaybeUninit] of the underlying type to a mutable slice of [std::mem::MaybeUninit] of the wrapper.
+
+            This is a zero-cost abstraction. */
+            {vis} fn from_maybe_uninit_slice_mut<'boil_c>(slice: &'boil_c mut [std::mem::MaybeUninit<{imp}{imp_generics}>]) -> &'boil_c mut [std::mem::MaybeUninit<{identifier}{wrap_generics_simple}>] {{
+                //safe because we're layout-compatible
+                unsafe {{ std::slice::from_raw_parts_mut(slice.as_mut_ptr() as *mut std::mem::MaybeUninit<{identifier}{wrap_generics_simple}>, slice.len()) }}
+            }}
+            /**
+            Converts from a mutable slice of [std::mem::MaybeUninit] of the wrapper to a mutable slice of [std::mem::MaybeUninit] of the underlying type.
+
+            This is a zero-cost abstraction. */
+            {vis} fn as_inner_maybe_uninit_slice_mut<'boil_c>(slice: &'boil_c mut [std::mem::MaybeUninit<Self>]) -> &'boil_c mut [std::mem::MaybeUninit<{imp}{imp_generics}>] {{
+                //safe because we're layout-compatible
+                unsafe {{ std::slice::from_raw_parts_mut(slice.as_mut_ptr() as *mut std::mem::MaybeUninit<{imp}{imp_generics}>, slice.len()) }}
+            }}
+        }}
+        ")
+    }
+    /**
+    Projects `std::sync::Weak` and `std::rc::Weak` through their raw pointers.
+
+    Shared by sized and unsized wrappers, since a pointer cast keeps an unsized pointer's metadata.
+    */
+    fn implement_weak(&self, vis: &str) -> String {
+        let identifier = &self.name;
+        let imp = &self.imp;
+        let wrap_generics_simple = &self.wrap_generics_simple;
+        let wrap_generics_complex = &self.wrap_generics_complex;
+        let imp_generics = &self.imp_generics;
+        let where_clause = &self.where_clause;
+        format!("
+        //Weak projections
+        impl {wrap_generics_complex} {identifier}{wrap_generics_simple} {where_clause} {{
+            /**
+            Converts from a [std::sync::Weak] of underlying type to a [std::sync::Weak] of the wrapper.
+
+            This is a zero-cost abstraction, and works for dangling pointers like `Weak::new()`. */
+            {vis} fn from_weak_arc(weak: std::sync::Weak<{imp}{imp_generics}>) -> std::sync::Weak<{identifier}{wrap_generics_simple}> {{
+                let f = std::sync::Weak::into_raw(weak) as *mut {identifier}{wrap_generics_simple};
+                //safe because we're layout-compatible, and the cast keeps the address that marks a dangling pointer
+                unsafe {{ std::sync::Weak::from_raw(f) }}
+            }}
+            /**
+            Converts from a [std::sync::Weak] of wrapper type to a [std::sync::Weak] of the underlying type.
+
+            This is a zero-cost abstraction, and works for dangling pointers like `Weak::new()`. */
+            {vis} fn to_weak_arc(weak: std::sync::Weak<Self>) -> std::sync::Weak<{imp}{imp_generics}> {{
+                let f = std::sync::Weak::into_raw(weak) as *mut {imp}{imp_generics};
+                //safe because we're layout-compatible, and the cast keeps the address that marks a dangling pointer
+                unsafe {{ std::sync::Weak::from_raw(f) }}
+            }}
+            /**
+            Converts from a [std::rc::Weak] of underlying type to a [std::rc::Weak] of the wrapper.
+
+            This is a zero-cost abstraction, and works for dangling pointers like `Weak::new()`. */
+            {vis} fn from_weak_rc(weak: std::rc::Weak<{imp}{imp_generics}>) -> std::rc::Weak<{identifier}{wrap_generics_simple}> {{
+                let f = std::rc::Weak::into_raw(weak) as *mut {identifier}{wrap_generics_simple};
+                //safe because we're layout-compatible, and the cast keeps the address that marks a dangling pointer
+                unsafe {{ std::rc::Weak::from_raw(f) }}
+            }}
+            /**
+            Converts from a [std::rc::Weak] of wrapper type to a [std::rc::Weak] of the underlying type.
+
+            This is a zero-cost abstraction, and works for dangling pointers like `Weak::new()`. */
+            {vis} fn to_weak_rc(weak: std::rc::Weak<Self>) -> std::rc::Weak<{imp}{imp_generics}> {{
+                let f = std::rc::Weak::into_raw(weak) as *mut {imp}{imp_generics};
+                //safe because we're layout-compatible, and the cast keeps the address that marks a dangling pointer
+                unsafe {{ std::rc::Weak::from_raw(f) }}
+            }}
+        }}
+        ")
+    }
+    ///Forwards IntoIterator, FromIterator and Extend to the payload, wherever the payload implements them; see [Self::where_clause_with_optional].
+    fn implement_iter(&self) -> String {
+        let identifier = &self.name;
+        let imp = &self.imp;
+        let wrap_generics_simple = &self.wrap_generics_simple;
+        let wrap_generics_complex = &self.wrap_generics_complex;
+        let imp_generics = &self.imp_generics;
+        let generics_lifetime = self.generics_with("'boil_a");
+        let generics_item = self.generics_with("BoilItem");
+        let into_iter_where = self.where_clause_with_optional(&format!("{imp}{imp_generics}: IntoIterator"));
+        let ref_where = self.where_clause_with_optional(&format!("&'boil_a {imp}{imp_generics}: IntoIterator"));
+        let mut_where = self.where_clause_with_optional(&format!("&'boil_a mut {imp}{imp_generics}: IntoIterator"));
+        let from_iter_where = self.where_clause_with(&format!("{imp}{imp_generics}: std::iter::FromIterator<BoilItem>"));
+        let extend_where = self.where_clause_with(&format!("{imp}{imp_generics}: Extend<BoilItem>"));
+        format!("
+        //iteration
+        impl {wrap_generics_complex} IntoIterator for {identifier}{wrap_generics_simple} {into_iter_where} {{
+            type Item = <{imp}{imp_generics} as IntoIterator>::Item;
+            type IntoIter = <{imp}{imp_generics} as IntoIterator>::IntoIter;
+            fn into_iter(self) -> Self::IntoIter {{
+                self.0.into_iter()
+            }}
+        }}
+        impl {generics_lifetime} IntoIterator for &'boil_a {identifier}{wrap_generics_simple} {ref_where} {{
+            type Item = <&'boil_a {imp}{imp_generics} as IntoIterator>::Item;
+            type IntoIter = <&'boil_a {imp}{imp_generics} as IntoIterator>::IntoIter;
+            fn into_iter(self) -> Self::IntoIter {{
+                (&self.0).into_iter()
+            }}
+        }}
+        impl {generics_lifetime} IntoIterator for &'boil_a mut {identifier}{wrap_generics_simple} {mut_where} {{
+            type Item = <&'boil_a mut {imp}{imp_generics} as IntoIterator>::Item;
+            type IntoIter = <&'boil_a mut {imp}{imp_generics} as IntoIterator>::IntoIter;
+            fn into_iter(self) -> Self::IntoIter {{
+                (&mut self.0).into_iter()
+            }}
+        }}
+        impl {generics_item} std::iter::FromIterator<BoilItem> for {identifier}{wrap_generics_simple} {from_iter_where} {{
+            fn from_iter<I: IntoIterator<Item=BoilItem>>(iter: I) -> Self {{
+                Self(std::iter::FromIterator::from_iter(iter))
+            }}
+        }}
+        impl {generics_item} Extend<BoilItem> for {identifier}{wrap_generics_simple} {extend_where} {{
+            fn extend<I: IntoIterator<Item=BoilItem>>(&mut self, iter: I) {{
+                self.0.extend(iter)
+            }}
+        }}
+        ")
+    }
+    ///Forwards Index and IndexMut to the payload, for any index type the payload accepts.
+    fn implement_index(&self) -> String {
+        let identifier = &self.name;
+        let imp = &self.imp;
+        let wrap_generics_simple = &self.wrap_generics_simple;
+        let imp_generics = &self.imp_generics;
+        let generics_index = self.generics_with("BoilIndex");
+        let index_where = self.where_clause_with(&format!("{imp}{imp_generics}: std::ops::Index<BoilIndex>"));
+        let index_mut_where = self.where_clause_with(&format!("{imp}{imp_generics}: std::ops::IndexMut<BoilIndex>"));
+        format!("
+        //index
+        impl {generics_index} std::ops::Index<BoilIndex> for {identifier}{wrap_generics_simple} {index_where} {{
+            type Output = <{imp}{imp_generics} as std::ops::Index<BoilIndex>>::Output;
+            fn index(&self, index: BoilIndex) -> &Self::Output {{
+                //fully qualified, since indexing sugar on a trait object would pick its supertrait's Index instead
+                <{imp}{imp_generics} as std::ops::Index<BoilIndex>>::index(&self.0, index)
+            }}
+        }}
+        impl {generics_index} std::ops::IndexMut<BoilIndex> for {identifier}{wrap_generics_simple} {index_mut_where} {{
+            fn index_mut(&mut self, index: BoilIndex) -> &mut Self::Output {{
+                <{imp}{imp_generics} as std::ops::IndexMut<BoilIndex>>::index_mut(&mut self.0, index)
+            }}
+        }}
+        ")
+    }
+    ///Implements Future by polling the pinned payload, converting the output if `future(output = ...)` was requested.
+    fn implement_future(&self, args: &BoilArgs) -> String {
+        let identifier = &self.name;
+        let imp = &self.imp;
+        let wrap_generics_simple = &self.wrap_generics_simple;
+        let wrap_generics_complex = &self.wrap_generics_complex;
+        let imp_generics = &self.imp_generics;
+        let payload_output = format!("<{imp}{imp_generics} as std::future::Future>::Output");
+        let (output, future_where, map) = match &args.future_output {
+            Some(output) => (
+                output.clone(),
+                self.where_clause_with(&format!("{imp}{imp_generics}: std::future::Future, {payload_output}: Into<{output}>")),
+                ".map(Into::into)",
+            ),
+            None => (
+                payload_output,
+                self.where_clause_with(&format!("{imp}{imp_generics}: std::future::Future")),
+                "",
+            ),
+        };
+        format!("
+        //future
+        impl {wrap_generics_complex} std::future::Future for {identifier}{wrap_generics_simple} {future_where} {{
+            type Output = {output};
+            fn poll(self: core::pin::Pin<&mut Self>, cx: &mut std::task::Context<'_>) -> std::task::Poll<Self::Output> {{
+                //safe because the payload is pinned whenever the wrapper is, as in the Pin projections
+                let payload = unsafe {{ self.map_unchecked_mut(|s| &mut s.0) }};
+                std::future::Future::poll(payload, cx){map}
+            }}
+        }}
+        ")
+    }
+    /**
+    Forwards the std::io traits to `payload`, the payload's type, including every method the payload may specialize.
+
+    Each trait is implemented wherever the payload implements it; see [Self::where_clause_with_optional].
+    */
+    fn implement_io(&self, payload: &str) -> String {
+        let identifier = &self.name;
+        let wrap_generics_simple = &self.wrap_generics_simple;
+        let wrap_generics_complex = &self.wrap_generics_complex;
+        let generics_lifetime = self.generics_with("'boil_a");
+        let mut code = String::new();
+        for IoTrait { name: io_trait, by_ref, methods } in IO {
+            let mut owned_methods = String::new();
+            let mut ref_methods = String::new();
+            for IoMethod { name: method, params, ret, args: forward } in *methods {
+                let params = if params.is_empty() { String::new() } else { format!(", {params}") };
+                let forward = if forward.is_empty() { String::new() } else { format!(", {forward}") };
+                owned_methods += &format!("
+            fn {method}(&mut self{params}) -> {ret} {{
+                std::io::{io_trait}::{method}(&mut self.0{forward})
+            }}");
+                ref_methods += &format!("
+            fn {method}(&mut self{params}) -> {ret} {{
+                let mut payload: &'boil_a {payload} = &self.0;
+                std::io::{io_trait}::{method}(&mut payload{forward})
+            }}");
+            }
+            let owned_where = self.where_clause_with_optional(&format!("{payload}: std::io::{io_trait}"));
+            code += &format!("
+        impl {wrap_generics_complex} std::io::{io_trait} for {identifier}{wrap_generics_simple} {owned_where} {{{owned_methods}
+        }}
+        ");
+            if *by_ref {
+                let ref_where = self.where_clause_with_optional(&format!("&'boil_a {payload}: std::io::{io_trait}"));
+                code += &format!("
+        impl {generics_lifetime} std::io::{io_trait} for &'boil_a {identifier}{wrap_generics_simple} {ref_where} {{{ref_methods}
+        }}
+        ");
+            }
+        }
+        code
+    }
+    ///Forwards the operators requested with `ops(...)` to the payload.
+    fn implement_ops(&self, args: &BoilArgs) -> String {
+        let identifier = &self.name;
+        let imp = &self.imp;
+        let wrap_generics_simple = &self.wrap_generics_simple;
+        let wrap_generics_complex = &self.wrap_generics_complex;
+        let imp_generics = &self.imp_generics;
+        let mut code = "".to_string();
+        for op in &args.ops {
+            let Op { trait_name: op, method, assign } = OPS.iter().find(|o| op.to_string() == o.trait_name).unwrap();
+            let where_clause = self.where_clause_with(&format!("{imp}{imp_generics}: std::ops::{op}<Output={imp}{imp_generics}>"));
+            match assign {
+                None => {
+                    code += &format!("
+        impl {wrap_generics_complex} std::ops::{op} for {identifier}{wrap_generics_simple} {where_clause} {{
+            type Output = Self;
+            fn {method}(self) -> Self {{
+                Self(std::ops::{op}::{method}(self.0))
+            }}
+        }}
+                    ");
+                }
+                Some((assign_op, assign_method)) => {
+                    let assign_where_clause = self.where_clause_with(&format!("{imp}{imp_generics}: std::ops::{assign_op}"));
+                    code += &format!("
+        impl {wrap_generics_complex} std::ops::{op} for {identifier}{wrap_generics_simple} {where_clause} {{
+            type Output = Self;
+            fn {method}(self, rhs: Self) -> Self {{
+                Self(std::ops::{op}::{method}(self.0, rhs.0))
+            }}
+        }}
+        impl {wrap_generics_complex} std::ops::{assign_op} for {identifier}{wrap_generics_simple} {assign_where_clause} {{
+            fn {assign_method}(&mut self, rhs: Self) {{
+                std::ops::{assign_op}::{assign_method}(&mut self.0, rhs.0)
+            }}
+        }}
+                    ");
+                    if args.mixed_ops {
+                        //wrapper op payload is still the wrapper, so arithmetic doesn't unwrap by accident
+                        code += &format!("
+        impl {wrap_generics_complex} std::ops::{op}<{imp}{imp_generics}> for {identifier}{wrap_generics_simple} {where_clause} {{
+            type Output = Self;
+            fn {method}(self, rhs: {imp}{imp_generics}) -> Self {{
+                Self(std::ops::{op}::{method}(self.0, rhs))
+            }}
+        }}
+        impl {wrap_generics_complex} std::ops::{assign_op}<{imp}{imp_generics}> for {identifier}{wrap_generics_simple} {assign_where_clause} {{
+            fn {assign_method}(&mut self, rhs: {imp}{imp_generics}) {{
+                std::ops::{assign_op}::{assign_method}(&mut self.0, rhs)
+            }}
+        }}
+                        ");
+                    }
+                }
+            }
+        }
+        code
+    }
+    fn implement_unsized(&self, args: &BoilArgs) -> String {
+        let wrap_generics_complex = &self.wrap_generics_complex;
+        let identifier = &self.name;
+        let where_clause = &self.where_clause;
+        let imp = &self.imp;
+        let imp_generics = &self.imp_generics;
+        let wrap_generics_simple = &self.wrap_generics_simple;
+        let vis = args.conversions_vis.as_ref().unwrap_or(&self.vis);
+        let mut impl_text = String::new();
+        if args.as_ref {
+            impl_text += &format!("
+        //asref
+        impl {wrap_generics_complex} AsRef<{identifier}{wrap_generics_simple}> for {imp}{imp_generics} {where_clause} {{
+             fn as_ref(&self) -> &{identifier}{wrap_generics_simple} {{
+                //safe because identifier is layout-compatible with `dyn Trait`
+                unsafe {{ std::mem::transmute(self) }}
              }}
         }}
         impl {wrap_generics_complex} AsRef<{imp}{imp_generics}> for {identifier}{wrap_generics_simple}  {where_clause} {{
@@ -644,6 +1958,10 @@ impl BoilParse {
                 &mut self.0
              }}
         }}
+        ");
+        }
+        if args.borrow {
+            impl_text += &format!("
         //borrow
         impl {wrap_generics_complex} std::borrow::Borrow<{identifier}{wrap_generics_simple}> for {imp}{imp_generics} {where_clause} {{
              fn borrow(&self) -> &{identifier}{wrap_generics_simple} {{
@@ -667,30 +1985,42 @@ impl BoilParse {
                 &mut self.0
              }}
         }}
+        ");
+        }
+        //the converted references share a lifetime, so the wrapper can't outlive the payload
+        let generics_lifetime = self.generics_with("'boil_r");
+        if args.from {
+            impl_text += &format!("
         //from/into
-        impl {wrap_generics_complex} From<&{imp}{imp_generics}> for &{identifier} {wrap_generics_simple} {where_clause} {{
-            fn from(t: &{imp}{imp_generics}) -> Self {{
-                unsafe {{ &*(t as *const _ as *const _) }}
+        impl {generics_lifetime} From<&'boil_r {imp}{imp_generics}> for &'boil_r {identifier} {wrap_generics_simple} {where_clause} {{
+            fn from(t: &'boil_r {imp}{imp_generics}) -> Self {{
+                //transmute required here since a pointer cast cannot change the trait object's lifetime
+                unsafe {{ std::mem::transmute(t) }}
             }}
         }}
-        impl {wrap_generics_complex} From<&{identifier} {wrap_generics_simple}> for &{imp} {imp_generics} {where_clause} {{
-            fn from(t: &{identifier} {wrap_generics_simple}) -> Self {{
+        impl {generics_lifetime} From<&'boil_r {identifier} {wrap_generics_simple}> for &'boil_r {imp} {imp_generics} {where_clause} {{
+            fn from(t: &'boil_r {identifier} {wrap_generics_simple}) -> Self {{
                 //transmute required here since wrapper not known to conform to payload type
                 unsafe {{ std::mem::transmute(t) }}
             }}
         }}
-        impl {wrap_generics_complex} From<&mut {imp}{imp_generics}> for &mut {identifier} {wrap_generics_simple} {where_clause} {{
-            fn from(t: &mut {imp}{imp_generics}) -> Self {{
-                unsafe {{ &mut *(t as *mut _ as *mut _) }}
+        impl {generics_lifetime} From<&'boil_r mut {imp}{imp_generics}> for &'boil_r mut {identifier} {wrap_generics_simple} {where_clause} {{
+            fn from(t: &'boil_r mut {imp}{imp_generics}) -> Self {{
+                //transmute required here since a pointer cast cannot change the trait object's lifetime
+                unsafe {{ std::mem::transmute(t) }}
             }}
         }}
-        impl {wrap_generics_complex} From<&mut {identifier} {wrap_generics_simple}> for &mut {imp} {imp_generics} {where_clause} {{
-            fn from(t: &mut {identifier} {wrap_generics_simple}) -> Self {{
+        impl {generics_lifetime} From<&'boil_r mut {identifier} {wrap_generics_simple}> for &'boil_r mut {imp} {imp_generics} {where_clause} {{
+            fn from(t: &'boil_r mut {identifier} {wrap_generics_simple}) -> Self {{
                 //transmute required here since wrapper not known to conform to payload type
                 unsafe {{ std::mem::transmute(t) }}
             }}
         }}
 
+        ");
+        }
+        if args.projections {
+            impl_text += &format!("
         //projections.  Box:
         impl {wrap_generics_complex} From<Box<{imp}{imp_generics}>> for Box<{identifier} {wrap_generics_simple}> {where_clause} {{
             fn from(t: Box<{imp}{imp_generics}>) -> Self {{
@@ -709,31 +2039,35 @@ impl BoilParse {
             }}
         }}
         //Pin:
-        impl {wrap_generics_complex} From<core::pin::Pin<&{imp}{imp_generics}>> for core::pin::Pin<&{identifier} {wrap_generics_simple}> {where_clause} {{
-            fn from(t: core::pin::Pin<&{imp}{imp_generics}>) -> Self {{
+        impl {generics_lifetime} From<core::pin::Pin<&'boil_r {imp}{imp_generics}>> for core::pin::Pin<&'boil_r {identifier} {wrap_generics_simple}> {where_clause} {{
+            fn from(t: core::pin::Pin<&'boil_r {imp}{imp_generics}>) -> Self {{
                 //safe because we're layout-compatible
                 unsafe {{
-                    let f = core::pin::Pin::into_inner_unchecked(t) as &_ as *const _ as *const {identifier} {wrap_generics_simple};
-                    core::pin::Pin::new_unchecked(&*f)
+                    let f = core::pin::Pin::into_inner_unchecked(t);
+                    //transmute required because a pointer cast cannot change the trait object's lifetime
+                    let g: &'boil_r {identifier} {wrap_generics_simple} = std::mem::transmute(f);
+                    core::pin::Pin::new_unchecked(g)
                 }}
 
             }}
         }}
         //PinMut:
-        impl {wrap_generics_complex} From<core::pin::Pin<&mut {imp}{imp_generics}>> for core::pin::Pin<&mut {identifier} {wrap_generics_simple}> {where_clause} {{
-            fn from(t: core::pin::Pin<&mut {imp}{imp_generics}>) -> Self {{
+        impl {generics_lifetime} From<core::pin::Pin<&'boil_r mut {imp}{imp_generics}>> for core::pin::Pin<&'boil_r mut {identifier} {wrap_generics_simple}> {where_clause} {{
+            fn from(t: core::pin::Pin<&'boil_r mut {imp}{imp_generics}>) -> Self {{
                 //safe because we're layout-compatible
                 unsafe {{
-                    let f = core::pin::Pin::into_inner_unchecked(t) as &mut _ as *mut _ as *mut {identifier} {wrap_generics_simple};
-                    core::pin::Pin::new_unchecked(&mut *f)
+                    let f = core::pin::Pin::into_inner_unchecked(t);
+                    //transmute required because a pointer cast cannot change the trait object's lifetime
+                    let g: &'boil_r mut {identifier} {wrap_generics_simple} = std::mem::transmute(f);
+                    core::pin::Pin::new_unchecked(g)
                 }}
 
             }}
         }}
 
         //other direction Pin:
-        impl {wrap_generics_complex} From<core::pin::Pin<&{identifier}{wrap_generics_simple}>> for core::pin::Pin<&{imp}{imp_generics}> {where_clause} {{
-            fn from(t: core::pin::Pin<&{identifier}{wrap_generics_simple}>) -> Self {{
+        impl {generics_lifetime} From<core::pin::Pin<&'boil_r {identifier}{wrap_generics_simple}>> for core::pin::Pin<&'boil_r {imp}{imp_generics}> {where_clause} {{
+            fn from(t: core::pin::Pin<&'boil_r {identifier}{wrap_generics_simple}>) -> Self {{
                 //safe because we're layout-compatible
                 unsafe {{
                     let f = core :: pin :: Pin :: into_inner_unchecked(t);
@@ -744,8 +2078,8 @@ impl BoilParse {
             }}
         }}
         //PinMut
-        impl {wrap_generics_complex} From<core::pin::Pin<&mut {identifier}{wrap_generics_simple}>> for core::pin::Pin<&mut {imp}{imp_generics}> {where_clause} {{
-            fn from(t: core::pin::Pin<&mut {identifier}{wrap_generics_simple}>) -> Self {{
+        impl {generics_lifetime} From<core::pin::Pin<&'boil_r mut {identifier}{wrap_generics_simple}>> for core::pin::Pin<&'boil_r mut {imp}{imp_generics}> {where_clause} {{
+            fn from(t: core::pin::Pin<&'boil_r mut {identifier}{wrap_generics_simple}>) -> Self {{
                 //safe because we're layout-compatible
                 unsafe {{
                     let f = core :: pin :: Pin :: into_inner_unchecked(t);
@@ -795,6 +2129,13 @@ impl BoilParse {
             }}
         }}
 
+        ");
+            impl_text += &self.implement_pin_pointers(vis);
+            impl_text += &self.implement_weak(vis);
+            impl_text += &self.implement_pointers(vis);
+        }
+        if args.from {
+            impl_text += &format!("
         //Result projections
         impl {wrap_generics_complex} {identifier} {wrap_generics_simple} {where_clause} {{
             /**
@@ -830,7 +2171,8 @@ impl BoilParse {
 
 
         ");
-        if !scoped {
+        }
+        if args.deref {
             impl_text += &format!("
         //deref and derefmut
         impl {wrap_generics_complex} std::ops::Deref for {identifier}{wrap_generics_simple} {where_clause} {{
@@ -838,13 +2180,25 @@ impl BoilParse {
             fn deref(&self) -> &Self::Target {{
                 &self.0
             }}
-        }}
+        }}");
+        }
+        if args.deref_mut {
+            impl_text += &format!("
         impl {wrap_generics_complex} std::ops::DerefMut for {identifier} {wrap_generics_simple} {where_clause} {{
             fn deref_mut(&mut self) -> &mut Self::Target {{
                 &mut self.0
             }}
         }}");
         }
+        if args.index {
+            impl_text += &self.implement_index();
+        }
+        if args.future {
+            impl_text += &self.implement_future(args);
+        }
+        if args.io {
+            impl_text += &self.implement_io(&format!("({}{} + 'static)", self.imp, self.imp_generics));
+        }
         impl_text
     }
 }
@@ -852,17 +2206,33 @@ impl BoilParse {
 
 #[proc_macro_attribute]
 pub fn boil(attr: TokenStream, item: TokenStream) -> TokenStream {
-    let scoped = match attr.into_iter().find(|p| p.to_string() == "scoped") {
-        Some(_) => true,
-        None => false,
-    };
-
     // println!("dbg boil");
     //we require types to be repr-transparent
     let mut code: TokenStream = "#[repr(transparent)]\n".parse().unwrap();
-    let parse = BoilParse::new(item.clone());
+    let args = match BoilArgs::new(attr) {
+        Ok(args) => args,
+        Err(err) => {
+            //emit the struct as written, so the error is the only one reported
+            let mut code = item;
+            code.extend(err);
+            return code;
+        }
+    };
+    if let Some(expanded) = args.expand_preset("boil", item.clone()) {
+        return expanded;
+    }
+    let parse = match BoilParse::new(item.clone()) {
+        Ok(parse) => parse,
+        Err(err) => {
+            //emit the struct as written, so the error is the only one reported
+            let mut code = item;
+            code.extend(err);
+            return code;
+        }
+    };
+    code.extend(args.derive_attributes());
     code.extend(item);
-    let parsed_implementation: TokenStream = parse.implement(scoped).parse().unwrap();
+    let parsed_implementation: TokenStream = parse.implement(&args).parse().unwrap();
     code.extend(parsed_implementation);
     // println!("will emit {}",code);
     code
@@ -870,51 +2240,559 @@ pub fn boil(attr: TokenStream, item: TokenStream) -> TokenStream {
 
 #[proc_macro_attribute]
 pub fn boil_unsized(attr: TokenStream, item: TokenStream) -> TokenStream {
-    let scoped = match attr.into_iter().find(|p| p.to_string() == "scoped") {
-        Some(_) => true,
-        None => false,
-    };
-    let parse = BoilParse::new(item.clone());
     let mut code: TokenStream = "#[repr(transparent)]\n".parse().unwrap();
+    let args = match BoilArgs::new(attr) {
+        Ok(args) => args,
+        Err(err) => {
+            //emit the struct as written, so the error is the only one reported
+            let mut code = item;
+            code.extend(err);
+            return code;
+        }
+    };
+    if let Some(expanded) = args.expand_preset("boil_unsized", item.clone()) {
+        return expanded;
+    }
+    if let Some(op) = args.ops.first() {
+        let mut code = item;
+        code.extend(compile_error("`ops` requires a sized payload", op.span()));
+        return code;
+    }
+    if let Some(const_new) = &args.const_new {
+        let mut code = item;
+        code.extend(compile_error("`const_new` requires a sized payload", const_new.span()));
+        return code;
+    }
+    if let Some(iter) = &args.iter {
+        let mut code = item;
+        code.extend(compile_error("`iter` requires a sized payload", iter.span()));
+        return code;
+    }
+    let parse = match BoilParse::new(item.clone()) {
+        Ok(parse) => parse,
+        Err(err) => {
+            //emit the struct as written, so the error is the only one reported
+            let mut code = item;
+            code.extend(err);
+            return code;
+        }
+    };
+    code.extend(args.derive_attributes());
     code.extend(item);
-    let parsed_implementation: TokenStream = parse.implement_unsized(scoped).parse().unwrap();
+    let parsed_implementation: TokenStream = parse.implement_unsized(&args).parse().unwrap();
     code.extend(parsed_implementation);
     // println!("emit {}",parsed_implementation);
     code
 }
 
-#[proc_macro_derive(Display)]
+/**
+Each preset becomes a `macro_rules!` macro.  `#[boil(preset = name)]` calls that macro with the item, and the macro calls
+`#[boil]` again with the preset's arguments followed by the remaining ones.
+*/
+#[proc_macro]
+pub fn preset(input: TokenStream) -> TokenStream {
+    let mut code = "".to_string();
+    let mut iter = input.into_iter().peekable();
+    while iter.peek().is_some() {
+        let mut attributes = "".to_string();
+        let mut vis = "".to_string();
+        //doc comments and other attributes
+        while let Some(TokenTree::Punct(p)) = iter.peek() {
+            if p.as_char() != '#' { break }
+            attributes += &iter.next().unwrap().to_string();
+            match iter.next() {
+                Some(TokenTree::Group(g)) if g.delimiter() == Delimiter::Bracket => {
+                    attributes += &g.to_string();
+                    attributes += "\n";
+                }
+                Some(other) => return compile_error("Expected an attribute after `#`", other.span()),
+                None => return compile_error("Expected an attribute after `#`", Span::call_site()),
+            }
+        }
+        if let Some(TokenTree::Ident(i)) = iter.peek() {
+            if i.to_string() == "pub" {
+                let public = iter.next().unwrap();
+                match iter.next() {
+                    Some(TokenTree::Group(g)) if g.delimiter() == Parenthesis => {
+                        vis = format!("pub{}", g);
+                    }
+                    _ => {
+                        return compile_error("Presets can't be `pub`; use a restricted visibility like `pub(crate)`", public.span());
+                    }
+                }
+            }
+        }
+        let name = match iter.next() {
+            Some(TokenTree::Ident(i)) => i,
+            Some(other) => return compile_error(&format!("Expected the name of a preset instead of `{}`", other), other.span()),
+            None => return compile_error("Expected the name of a preset", Span::call_site()),
+        };
+        match iter.next() {
+            Some(TokenTree::Punct(p)) if p.as_char() == '=' => {}
+            Some(other) => return compile_error(&format!("Expected `=` after the preset name instead of `{}`", other), other.span()),
+            None => return compile_error("Expected `=` after the preset name", name.span()),
+        }
+        let mut args = TokenStream::new();
+        loop {
+            match iter.next() {
+                None => break,
+                Some(TokenTree::Punct(p)) if p.as_char() == ';' => break,
+                Some(other) => args.extend([other]),
+            }
+        }
+        //check the arguments now, so mistakes are reported where the preset is declared
+        if let Err(err) = BoilArgs::new(args.clone()) {
+            return err;
+        }
+        //an empty preset passes along only the remaining arguments
+        let separator = if args.is_empty() { "" } else { "," };
+        //the macro is expanded in the caller's crate, so `$crate` would name that crate rather than boil
+        code += &format!("
+        {attributes}
+        macro_rules! {name} {{
+            ($macro:ident [$($args:tt)*] $($item:tt)*) => {{
+                #[::boil::$macro({args}{separator} $($args)*)]
+                $($item)*
+            }};
+        }}
+        ");
+        if !vis.is_empty() {
+            code += &format!("{vis} use {name};\n");
+        }
+    }
+    code.parse().unwrap()
+}
+
+///Whether `literal` is a string that can be used as a format string, either plain like `"widget#{}"` or raw like `r#"widget "{}""#`.
+fn is_format_string(literal: &Literal) -> bool {
+    let text = literal.to_string();
+    text.starts_with('"') || text.starts_with("r\"") || text.starts_with("r#")
+}
+
+/**
+Writes the payload with a format string like `"widget#{}"`, where the payload is the only positional argument.
+
+Returns the body along with the formatting traits the placeholders need from the payload, like `std::fmt::LowerHex` for `{:x}`.
+*/
+fn write_format(format: &Literal) -> (String, Vec<&'static str>) {
+    let text = format.to_string();
+    let mut chars = text.chars();
+    let mut payload_traits = Vec::new();
+    while let Some(c) = chars.next() {
+        if c != '{' {
+            continue;
+        }
+        //`{{` is an escaped brace rather than a placeholder
+        if chars.clone().next() == Some('{') {
+            chars.next();
+            continue;
+        }
+        let placeholder: String = chars.by_ref().take_while(|&c| c != '}').collect();
+        //the type is the last character of the spec after `:`, like `x` in `{:#010x}`
+        let spec = placeholder.split_once(':').map(|(_, spec)| spec).unwrap_or("");
+        let payload_trait = if spec.ends_with('?') {
+            "std::fmt::Debug"
+        }
+        else {
+            match spec.chars().last() {
+                Some('x') => "std::fmt::LowerHex",
+                Some('X') => "std::fmt::UpperHex",
+                Some('o') => "std::fmt::Octal",
+                Some('b') => "std::fmt::Binary",
+                Some('e') => "std::fmt::LowerExp",
+                Some('E') => "std::fmt::UpperExp",
+                Some('p') => "std::fmt::Pointer",
+                _ => "std::fmt::Display",
+            }
+        };
+        if !payload_traits.contains(&payload_trait) {
+            payload_traits.push(payload_trait);
+        }
+    }
+    //a format string without placeholders can't be passed an argument
+    let body = if payload_traits.is_empty() {
+        format!("write!(formatter, {text})")
+    }
+    else {
+        format!("write!(formatter, {text}, &self.0)")
+    };
+    (body, payload_traits)
+}
+
+///The where clause with the payload bound by each of `payload_traits`.
+fn payload_where_clause(parsed: &BoilParse, payload_traits: &[&str]) -> String {
+    if payload_traits.is_empty() {
+        parsed.where_clause.clone()
+    }
+    else {
+        parsed.where_clause_with(&format!("{}{}: {}", parsed.imp, parsed.imp_generics, payload_traits.join(" + ")))
+    }
+}
+
+#[proc_macro_derive(Display, attributes(display))]
 pub fn display(item: TokenStream) -> TokenStream {
-    let parsed = BoilParse::new(item);
+    let parsed = match BoilParse::new(item) {
+        Ok(parsed) => parsed,
+        Err(err) => return err,
+    };
+    let attributes = match parsed.helper_attributes("display") {
+        Ok(attributes) => attributes,
+        Err(err) => return err,
+    };
+    let mut body = "std::fmt::Display::fmt(&self.0,formatter)".to_string();
+    //the formatting traits the payload needs
+    let mut payload_traits = vec!["std::fmt::Display"];
+    for contents in attributes {
+        let mut iter = contents.clone().into_iter();
+        match iter.next() {
+            Some(TokenTree::Literal(l)) if is_format_string(&l) => {
+                if let Some(extra) = iter.next() {
+                    return compile_error("Expected only a format string; the payload is its only argument", extra.span());
+                }
+                (body, payload_traits) = write_format(&l);
+            }
+            _ => {
+                let args = match BoilArg::parse_all(contents) {
+                    Ok(args) => args,
+                    Err(err) => return err,
+                };
+                for arg in args {
+                    if arg.name.to_string() != "debug" {
+                        return compile_error(&format!("Unknown argument `{}`.  Expected a format string like `\"widget#{{}}\"`, or `debug`", arg.name), arg.name.span());
+                    }
+                    if let Err(err) = arg.expect_flag() {
+                        return err;
+                    }
+                    body = "std::fmt::Debug::fmt(&self.0,formatter)".to_string();
+                    payload_traits = vec!["std::fmt::Debug"];
+                }
+            }
+        }
+    }
+    let where_clause = payload_where_clause(&parsed, &payload_traits);
     let wrap_generics_complex = parsed.wrap_generics_complex;
     let wrap_generics_simple = parsed.wrap_generics_simple;
-    let where_clause = parsed.where_clause;
     let identifier = parsed.name;
     format!("
         impl {wrap_generics_complex} std::fmt::Display for {identifier} {wrap_generics_simple} {where_clause} {{
             fn fmt(&self, formatter: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {{
-                std::fmt::Display::fmt(&self.0,formatter)
+                {body}
+            }}
+        }}
+    ").parse().unwrap()
+}
+
+/**
+Forwards `payload_trait`, like `std::hash::Hash`, to the payload, with `items` as the body of the impl.
+
+The wrapper implements the trait wherever the payload does.  `more` can add impls with the same bound, like comparisons
+between the wrapper and the payload; it's given the parsed wrapper and the where clause.
+*/
+fn derive_forward(item: TokenStream, payload_trait: &str, items: &str, more: impl FnOnce(&BoilParse, &str) -> String) -> TokenStream {
+    let parsed = match BoilParse::new(item) {
+        Ok(parsed) => parsed,
+        Err(err) => return err,
+    };
+    let where_clause = parsed.where_clause_with(&format!("{}{}: {payload_trait}", parsed.imp, parsed.imp_generics));
+    let wrap_generics_complex = &parsed.wrap_generics_complex;
+    let wrap_generics_simple = &parsed.wrap_generics_simple;
+    let identifier = &parsed.name;
+    let mut code = format!("
+        impl {wrap_generics_complex} {payload_trait} for {identifier} {wrap_generics_simple} {where_clause} {{{items}
+        }}
+    ");
+    code += &more(&parsed, &where_clause);
+    code.parse().unwrap()
+}
+
+///Forwards a formatting trait like `LowerHex` to the payload.
+fn derive_fmt(item: TokenStream, fmt_trait: &str) -> TokenStream {
+    derive_forward(item, &format!("std::fmt::{fmt_trait}"), &format!("
+            fn fmt(&self, formatter: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {{
+                std::fmt::{fmt_trait}::fmt(&self.0,formatter)
+            }}"), |_, _| String::new())
+}
+
+#[proc_macro_derive(LowerHex)]
+pub fn lower_hex(item: TokenStream) -> TokenStream {
+    derive_fmt(item, "LowerHex")
+}
+
+#[proc_macro_derive(UpperHex)]
+pub fn upper_hex(item: TokenStream) -> TokenStream {
+    derive_fmt(item, "UpperHex")
+}
+
+#[proc_macro_derive(Octal)]
+pub fn octal(item: TokenStream) -> TokenStream {
+    derive_fmt(item, "Octal")
+}
+
+#[proc_macro_derive(Binary)]
+pub fn binary(item: TokenStream) -> TokenStream {
+    derive_fmt(item, "Binary")
+}
+
+#[proc_macro_derive(LowerExp)]
+pub fn lower_exp(item: TokenStream) -> TokenStream {
+    derive_fmt(item, "LowerExp")
+}
+
+#[proc_macro_derive(UpperExp)]
+pub fn upper_exp(item: TokenStream) -> TokenStream {
+    derive_fmt(item, "UpperExp")
+}
+
+#[proc_macro_derive(Pointer)]
+pub fn pointer(item: TokenStream) -> TokenStream {
+    derive_fmt(item, "Pointer")
+}
+
+#[proc_macro_derive(Debug, attributes(debug))]
+pub fn debug(item: TokenStream) -> TokenStream {
+    let parsed = match BoilParse::new(item) {
+        Ok(parsed) => parsed,
+        Err(err) => return err,
+    };
+    let args = match parsed.helper_arguments("debug") {
+        Ok(args) => args,
+        Err(err) => return err,
+    };
+    let mut prefix = None;
+    for arg in args {
+        match (arg.name.to_string().as_str(), &arg.value) {
+            ("prefix", BoilArgValue::Flag) => {
+                prefix = Some(format!("\"{}\"", parsed.name));
+            }
+            ("prefix", BoilArgValue::Assign(value)) => {
+                match value.clone().into_iter().next() {
+                    Some(TokenTree::Literal(l)) if l.to_string().starts_with('"') => {
+                        prefix = Some(l.to_string());
+                    }
+                    _ => return compile_error("Expected a string like `prefix = \"Name\"`", arg.name.span()),
+                }
+            }
+            _ => {
+                return compile_error(&format!("Unknown argument `{}`.  Expected `prefix` or `prefix = \"Name\"`", arg.name), arg.name.span());
+            }
+        }
+    }
+    let where_clause = parsed.where_clause_with(&format!("{}{}: std::fmt::Debug", parsed.imp, parsed.imp_generics));
+    let wrap_generics_complex = parsed.wrap_generics_complex;
+    let wrap_generics_simple = parsed.wrap_generics_simple;
+    let identifier = parsed.name;
+    let body = match prefix {
+        //`&&` so unsized payloads coerce to `&dyn Debug` through `&T: Debug`
+        Some(prefix) => format!("formatter.debug_tuple({prefix}).field(&&self.0).finish()"),
+        None => "std::fmt::Debug::fmt(&self.0,formatter)".to_string(),
+    };
+    format!("
+        impl {wrap_generics_complex} std::fmt::Debug for {identifier} {wrap_generics_simple} {where_clause} {{
+            fn fmt(&self, formatter: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {{
+                {body}
             }}
         }}
     ").parse().unwrap()
 }
 
-#[proc_macro_derive(Error)]
+#[proc_macro_derive(Default)]
+pub fn default(item: TokenStream) -> TokenStream {
+    derive_forward(item, "std::default::Default", "
+            fn default() -> Self {
+                Self(std::default::Default::default())
+            }", |_, _| String::new())
+}
+
+#[proc_macro_derive(Clone)]
+pub fn clone(item: TokenStream) -> TokenStream {
+    derive_forward(item, "std::clone::Clone", "
+            fn clone(&self) -> Self {
+                Self(std::clone::Clone::clone(&self.0))
+            }
+            fn clone_from(&mut self, source: &Self) {
+                std::clone::Clone::clone_from(&mut self.0, &source.0)
+            }", |_, _| String::new())
+}
+
+#[proc_macro_derive(Copy)]
+pub fn copy(item: TokenStream) -> TokenStream {
+    derive_forward(item, "std::marker::Copy", "", |_, _| String::new())
+}
+
+#[proc_macro_derive(PartialEq)]
+pub fn partial_eq(item: TokenStream) -> TokenStream {
+    derive_forward(item, "std::cmp::PartialEq", "
+            fn eq(&self, other: &Self) -> bool {
+                std::cmp::PartialEq::eq(&self.0, &other.0)
+            }", |parsed, where_clause| {
+        let BoilParse { name: identifier, wrap_generics_complex, wrap_generics_simple, imp, imp_generics, .. } = parsed;
+        format!("
+        //cross-type equality, so wrapper == payload
+        impl {wrap_generics_complex} std::cmp::PartialEq<{imp}{imp_generics}> for {identifier} {wrap_generics_simple} {where_clause} {{
+            fn eq(&self, other: &{imp}{imp_generics}) -> bool {{
+                std::cmp::PartialEq::eq(&self.0, other)
+            }}
+        }}
+        impl {wrap_generics_complex} std::cmp::PartialEq<{identifier} {wrap_generics_simple}> for {imp}{imp_generics} {where_clause} {{
+            fn eq(&self, other: &{identifier} {wrap_generics_simple}) -> bool {{
+                std::cmp::PartialEq::eq(self, &other.0)
+            }}
+        }}
+        ")
+    })
+}
+
+#[proc_macro_derive(Eq)]
+pub fn eq(item: TokenStream) -> TokenStream {
+    derive_forward(item, "std::cmp::Eq", "", |_, _| String::new())
+}
+
+#[proc_macro_derive(PartialOrd)]
+pub fn partial_ord(item: TokenStream) -> TokenStream {
+    derive_forward(item, "std::cmp::PartialOrd", "
+            fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+                std::cmp::PartialOrd::partial_cmp(&self.0, &other.0)
+            }", |parsed, where_clause| {
+        let BoilParse { name: identifier, wrap_generics_complex, wrap_generics_simple, imp, imp_generics, .. } = parsed;
+        format!("
+        //cross-type comparison, so wrapper < payload
+        impl {wrap_generics_complex} std::cmp::PartialOrd<{imp}{imp_generics}> for {identifier} {wrap_generics_simple} {where_clause} {{
+            fn partial_cmp(&self, other: &{imp}{imp_generics}) -> Option<std::cmp::Ordering> {{
+                std::cmp::PartialOrd::partial_cmp(&self.0, other)
+            }}
+        }}
+        impl {wrap_generics_complex} std::cmp::PartialOrd<{identifier} {wrap_generics_simple}> for {imp}{imp_generics} {where_clause} {{
+            fn partial_cmp(&self, other: &{identifier} {wrap_generics_simple}) -> Option<std::cmp::Ordering> {{
+                std::cmp::PartialOrd::partial_cmp(self, &other.0)
+            }}
+        }}
+        ")
+    })
+}
+
+#[proc_macro_derive(Ord)]
+pub fn ord(item: TokenStream) -> TokenStream {
+    derive_forward(item, "std::cmp::Ord", "
+            fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+                std::cmp::Ord::cmp(&self.0, &other.0)
+            }", |_, _| String::new())
+}
+
+#[proc_macro_derive(Hash)]
+pub fn hash(item: TokenStream) -> TokenStream {
+    //hash exactly like the payload, since `Borrow<Payload>` lets maps look up the wrapper by payload
+    derive_forward(item, "std::hash::Hash", "
+            fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+                std::hash::Hash::hash(&self.0, state)
+            }", |_, _| String::new())
+}
+
+#[proc_macro_derive(FromStr, attributes(from_str))]
+pub fn from_str(item: TokenStream) -> TokenStream {
+    let parsed = match BoilParse::new(item) {
+        Ok(parsed) => parsed,
+        Err(err) => return err,
+    };
+    let args = match parsed.helper_arguments("from_str") {
+        Ok(args) => args,
+        Err(err) => return err,
+    };
+    let mut error = None;
+    for arg in args {
+        match (arg.name.to_string().as_str(), &arg.value) {
+            ("error", BoilArgValue::Assign(value)) => {
+                error = Some(value.to_string());
+            }
+            _ => {
+                return compile_error(&format!("Unknown argument `{}`.  Expected `error = ErrorType`", arg.name), arg.name.span());
+            }
+        }
+    }
+    let imp = &parsed.imp;
+    let imp_generics = &parsed.imp_generics;
+    let where_clause = match &error {
+        Some(error) => parsed.where_clause_with(&format!("{imp}{imp_generics}: std::str::FromStr, <{imp}{imp_generics} as std::str::FromStr>::Err: Into<{error}>")),
+        None => parsed.where_clause_with(&format!("{imp}{imp_generics}: std::str::FromStr")),
+    };
+    let error = error.unwrap_or_else(|| format!("<{imp}{imp_generics} as std::str::FromStr>::Err"));
+    let wrap_generics_complex = &parsed.wrap_generics_complex;
+    let wrap_generics_simple = &parsed.wrap_generics_simple;
+    let identifier = &parsed.name;
+    format!("
+        impl {wrap_generics_complex} std::str::FromStr for {identifier} {wrap_generics_simple} {where_clause} {{
+            type Err = {error};
+            fn from_str(s: &str) -> Result<Self, Self::Err> {{
+                //like from_result, the error converts with Into
+                <{imp}{imp_generics} as std::str::FromStr>::from_str(s).map(Self).map_err(|e| e.into())
+            }}
+        }}
+    ").parse().unwrap()
+}
+
+#[proc_macro_derive(Error, attributes(error))]
 pub fn error(item: TokenStream) -> TokenStream {
-    let parsed = BoilParse::new(item);
+    let parsed = match BoilParse::new(item) {
+        Ok(parsed) => parsed,
+        Err(err) => return err,
+    };
+    let attributes = match parsed.helper_attributes("error") {
+        Ok(attributes) => attributes,
+        Err(err) => return err,
+    };
+    //payload as source, rather than forwarding the payload's own source
+    let mut source = false;
+    let mut message = None;
+    for contents in attributes {
+        let mut iter = contents.clone().into_iter();
+        match iter.next() {
+            Some(TokenTree::Literal(l)) if is_format_string(&l) => {
+                if let Some(extra) = iter.next() {
+                    return compile_error("Expected only a format string; the payload is its only argument", extra.span());
+                }
+                source = true;
+                message = Some(write_format(&l));
+            }
+            _ => {
+                let args = match BoilArg::parse_all(contents) {
+                    Ok(args) => args,
+                    Err(err) => return err,
+                };
+                for arg in args {
+                    if arg.name.to_string() != "source" {
+                        return compile_error(&format!("Unknown argument `{}`.  Expected a message like `\"failed to open\"`, or `source`", arg.name), arg.name.span());
+                    }
+                    if let Err(err) = arg.expect_flag() {
+                        return err;
+                    }
+                    source = true;
+                }
+            }
+        }
+    }
+    //the payload is returned as a `dyn Error + 'static` when it's the source
+    let payload_bound = if source { "std::error::Error + 'static" } else { "std::error::Error" };
+    let where_clause = parsed.where_clause_with(&format!("{}{}: {payload_bound}", parsed.imp, parsed.imp_generics));
+    let message = message.map(|(message, payload_traits)| (message, payload_where_clause(&parsed, &payload_traits)));
     let wrap_generics_complex = parsed.wrap_generics_complex;
     let wrap_generics_simple = parsed.wrap_generics_simple;
-    let where_clause = parsed.where_clause;
 
     let identifier = parsed.name;
-    let code =
+    let source_body = if source { "Some(&self.0)" } else { "self.0.source()" };
+    let mut code =
     format!("
         impl {wrap_generics_complex} std::error::Error for {identifier} {wrap_generics_simple} {where_clause} {{
-            fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {{ self.0.source() }}
-            fn description(&self) -> &str {{ self.0.description() }}
-            fn cause(&self) -> Option<&dyn std::error::Error> {{ self.0.cause() }}
+            fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {{ {source_body} }}
         }}
     ");
+    if let Some((message, display_where_clause)) = message {
+        code += &format!("
+        impl {wrap_generics_complex} std::fmt::Display for {identifier} {wrap_generics_simple} {display_where_clause} {{
+            fn fmt(&self, formatter: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {{
+                {message}
+            }}
+        }}
+        ");
+    }
     // println!("emitting {}",code);
     code.parse().unwrap()
-}
\ No newline at end of file
+}
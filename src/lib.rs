@@ -269,7 +269,94 @@ let i: Arc<u8> = Arc::new(Foo(1)).to_arc();
 let o: Arc<Foo> = Foo::from_arc(Arc::new(2));
 ```
 
-**Warning**: These conversions have the same visibility as the inner field, which is private by default.
+Weak pointers project with `from_weak_arc`, `to_weak_arc`, `from_weak_rc` and `to_weak_rc`, including dangling
+ones from `Weak::new()`:
+```
+# use boil::boil;
+# #[boil] struct Foo(u8);
+use std::sync::{Arc, Weak};
+let strong = Arc::new(Foo(1));
+let weak: Weak<u8> = Foo::to_weak_arc(Arc::downgrade(&strong));
+assert_eq!(weak.upgrade().as_deref(), Some(&1));
+let dangling: Weak<Foo> = Foo::from_weak_arc(Weak::new());
+assert!(dangling.upgrade().is_none());
+```
+
+Pinned owning pointers project too: `Pin<Box<T>>` with From/Into, and `Pin<Arc<T>>` and `Pin<Rc<T>>` with
+`from_pin_arc`, `to_pin_arc`, `from_pin_rc` and `to_pin_rc`.  The payload stays pinned throughout.
+```
+# use boil::boil;
+# #[boil] struct Foo(u8);
+use std::pin::Pin;
+let b: Pin<Box<Foo>> = Box::pin(1).into();
+let a: Pin<std::sync::Arc<u8>> = Foo::from_pin_arc(std::sync::Arc::pin(2)).to_pin_arc();
+```
+
+Slices project with `from_slice` and `from_slice_mut`, and back with `as_inner_slice` and `as_inner_slice_mut`,
+without copying:
+```
+# use boil::boil;
+# #[boil] struct Foo(u8);
+let bytes = [1, 2, 3];
+let foos: &[Foo] = Foo::from_slice(&bytes);
+assert_eq!(*foos[1], 2);
+let back: &[u8] = Foo::as_inner_slice(foos);
+assert_eq!(back, &bytes);
+```
+
+Owned collections project the same way, reusing their allocation: `from_vec` and `into_inner_vec` for [Vec],
+and likewise `from_boxed_slice`, `from_arc_slice` and `from_rc_slice` and their `into_inner_` counterparts for
+`Box<[T]>`, `Arc<[T]>` and `Rc<[T]>`.
+```
+# use boil::boil;
+# #[boil] struct Foo(u8);
+let foos: Vec<Foo> = Foo::from_vec(vec![1, 2, 3]);
+let bytes: Vec<u8> = Foo::into_inner_vec(foos);
+let shared: std::rc::Rc<[Foo]> = Foo::from_rc_slice(bytes.into());
+assert_eq!(*shared[2], 3);
+```
+
+The std cells that are layout-compatible with their contents project too: `from_cell` and `as_inner_cell` for
+`&Cell<T>`, `from_unsafe_cell` and `as_inner_unsafe_cell` for `&UnsafeCell<T>`, `from_manually_drop` and
+`into_inner_manually_drop` for [ManuallyDrop](std::mem::ManuallyDrop), and for [MaybeUninit](std::mem::MaybeUninit),
+`from_maybe_uninit` and `into_inner_maybe_uninit`, with `_mut`, `_slice` and `_slice_mut` variants for references and slices.
+```
+# use boil::boil;
+# #[boil] struct Foo(u8);
+use std::mem::MaybeUninit;
+let mut buffer = [MaybeUninit::<u8>::uninit(); 2];
+for slot in Foo::from_maybe_uninit_slice_mut(&mut buffer) {
+    slot.write(Foo(3));
+}
+let counter = std::cell::Cell::new(Foo(1));
+Foo::as_inner_cell(&counter).set(2);
+assert_eq!(*counter.into_inner(), 2);
+```
+
+For FFI, raw pointers cast with `from_ptr`, `from_ptr_mut`, `as_inner_ptr` and `as_inner_ptr_mut`, and
+[NonNull](std::ptr::NonNull) with `from_non_null` and `as_inner_non_null`.  With [boil_unsized], the casts keep the
+pointer's metadata, such as a trait object's vtable.
+```
+# use boil::boil;
+# #[boil] struct Foo(u8);
+let raw: *const u8 = &1;
+let wrapped: *const Foo = Foo::from_ptr(raw);
+assert_eq!(Foo::as_inner_ptr(wrapped), raw);
+```
+
+[Option] projects with `from_option` and `into_option`, along with `_ref`, `_mut` and `_box` variants for
+`Option<&T>`, `Option<&mut T>` and `Option<Box<T>>`.  These avoid `.map(AsRef::as_ref)`, whose inference is ambiguous
+since [AsRef] is implemented in both directions.
+```
+# use boil::boil;
+# #[boil] struct Foo(u8);
+let focused: Option<&u8> = Some(&1);
+let f: Option<&Foo> = Foo::from_option_ref(focused);
+assert_eq!(Foo::into_option_ref(f), Some(&1));
+```
+
+**Warning**: These conversions have the same visibility as the inner field, which is private by default.  To choose their visibility
+separately, see the `conversions` [argument](#arguments).
 
 ## Result
 
@@ -310,7 +397,151 @@ fn return_wrapped(src: Result<u8,RecvTimeoutError>) -> Result<MyOk, RecvTimeoutE
 }
 ```
 
-**Warning**: These conversions have the same visibility as the inner field, which is private by default.
+For borrowed values, `from_result_ref` and `into_result_ref` convert `Result<&T, E>`, and `from_result_mut` and
+`into_result_mut` convert `Result<&mut T, E>`, mapping the error in the same way.
+
+```
+# use boil::boil;
+# use std::sync::mpsc::RecvTimeoutError;
+# #[boil] struct MyOk(u8);
+fn borrow_wrapped(src: Result<&u8, RecvTimeoutError>) -> Result<&MyOk, RecvTimeoutError> {
+    MyOk::from_result_ref(src)
+}
+```
+
+**Warning**: These conversions have the same visibility as the inner field, which is private by default.  To choose their visibility
+separately, see the `conversions` [argument](#arguments).
+
+## Const
+
+Since trait methods can't be `const`, [From] and [Into] can't be used in `const` and `static` items.  With `const_new`,
+the wrapper gets `const fn new(inner)` and `const fn into_inner(self)`, with the same visibility as the field.
+
+```
+# use boil::boil;
+#[boil(const_new)]
+struct Millis(u64);
+const TIMEOUT: Millis = Millis::new(500);
+const RAW: u64 = Millis::new(500).into_inner();
+```
+
+## Iteration
+
+With `iter`, collection wrappers forward [IntoIterator] (for the wrapper, `&Wrapper` and `&mut Wrapper`),
+[FromIterator] and [Extend] to the payload.  Each is implemented wherever the payload implements it.
+
+```
+# use boil::boil;
+#[boil(iter)]
+struct Widgets(Vec<u32>);
+let mut widgets: Widgets = (0..3).collect();
+widgets.extend([3]);
+for w in &mut widgets {
+    *w += 1;
+}
+let mut total = 0;
+for w in &widgets {
+    total += w;
+}
+assert_eq!(total, 10);
+```
+
+## Indexing
+
+With `index`, the wrapper forwards [Index](std::ops::Index) and [IndexMut](std::ops::IndexMut) to the payload, for any index type
+the payload accepts.  This also works with `scoped`, where indexing through Deref isn't available.
+
+```
+# use boil::boil;
+#[boil(scoped, index)]
+pub struct Table(Vec<u8>);
+let mut t: Table = vec![1, 2, 3].into();
+t[0] = 4;
+assert_eq!(t[0], 4);
+assert_eq!(&t[1..], &[2, 3]);
+```
+
+## Futures
+
+With `future`, a wrapper over a future is itself a [Future](std::future::Future), polling the pinned payload.
+Use `future(output = Type)` to convert the payload's output into `Type` with [Into], for example into another boiled type.
+
+```
+# use boil::boil;
+# use std::future::Future;
+#[boil]
+struct Bytes(Vec<u8>);
+#[boil(future(output = Bytes))]
+struct ReadOp(std::future::Ready<Vec<u8>>);
+fn assert_future<F: Future<Output = Bytes>>(_: F) {}
+assert_future(ReadOp(std::future::ready(vec![1])));
+```
+
+## I/O
+
+With `io`, the wrapper forwards [Read](std::io::Read), [Write](std::io::Write), [Seek](std::io::Seek) and
+[BufRead](std::io::BufRead) to the payload, each wherever the payload implements it.  `&Wrapper` implements
+Read, Write and Seek wherever `&Payload` does, as `&File` does.
+
+Every method the payload may specialize, such as `read_exact` or `write_vectored`, is forwarded too,
+so the wrapper performs like the payload.
+
+```
+# use boil::boil;
+use std::io::{Read, Write};
+#[boil(io)]
+struct Pipe(std::io::Cursor<Vec<u8>>);
+let mut p = Pipe(Default::default());
+p.write_all(b"hello").unwrap();
+p.set_position(0);
+let mut s = String::new();
+p.read_to_string(&mut s).unwrap();
+assert_eq!(s, "hello");
+```
+
+## Operators
+
+Numeric wrappers can forward [std::ops] operators to the payload with `ops(...)`.  Each binary operator, like
+[std::ops::Add], also gets its assigning variant, like [std::ops::AddAssign].  The supported operators are `Add`, `Sub`,
+`Mul`, `Div`, `Rem`, `BitAnd`, `BitOr`, `BitXor`, `Shl`, `Shr`, `Neg` and `Not`.
+
+```
+# use boil::boil;
+#[boil(ops(Add, Sub, Neg))]
+#[derive(Debug, PartialEq)]
+struct Pixels(i32);
+let mut p = Pixels(2) + Pixels(3);
+p -= Pixels(1);
+assert_eq!(-p, Pixels(-4));
+```
+
+With `mixed`, binary operators also accept the payload on the right-hand side.  The result is still the wrapper, so
+arithmetic doesn't silently unwrap to the payload.
+
+```
+# use boil::boil;
+#[boil(ops(Mul, mixed))]
+struct Pixels(i32);
+let p: Pixels = Pixels(2) * 3;
+```
+
+## Derives
+
+boil provides derives that forward a trait to the payload, like [Display](macro@Display), [Debug](macro@Debug),
+[Clone](macro@Clone) and [PartialEq](macro@PartialEq).  Each is bounded on the payload implementing the trait,
+so a generic wrapper implements it exactly when its payload does.  `#[derive(Clone)]` from std instead requires
+every type parameter to be `Clone`, whether the payload needs that or not.
+
+```
+# use boil::boil;
+use std::rc::Rc;
+struct NotClone;
+#[boil]
+#[derive(boil::Clone)]
+struct Shared<G>(Rc<G>);
+let s = Shared(Rc::new(NotClone));
+let _ = s.clone();
+```
 
 ## Memory layout
 
@@ -323,6 +554,66 @@ let v: u8 = 5;
 let u: *const Foo = unsafe { std::mem::transmute(&v) };
 ```
 
+# Arguments
+
+Every family of behavior above is implemented by default.  You can opt out of individual families with arguments:
+
+| Argument         | Disables                                                          |
+|------------------|-------------------------------------------------------------------|
+| `no_as_ref`      | [AsRef], [AsMut]                                                  |
+| `no_borrow`      | [std::borrow::Borrow], [std::borrow::BorrowMut]                   |
+| `no_from`        | [From], [Into], and the Result conversions built on them          |
+| `no_projections` | [Box], [std::pin::Pin], [std::sync::Arc], [std::rc::Rc], slice, [Vec], [Option], cell and pointer projections |
+| `no_deref`       | [std::ops::Deref], [std::ops::DerefMut]                           |
+| `no_deref_mut`   | [std::ops::DerefMut]                                              |
+| `scoped`         | Same as `no_deref`, see [Field](#field)                           |
+
+Other arguments:
+
+* `derive(...)` adds `#[derive(...)]` to the struct.  This is mostly useful in [presets](preset).
+* `preset = name` applies the arguments of a [preset].
+* `conversions = vis` sets the visibility of conversion functions, see below.
+* `ops(...)` forwards operators, see [Operators](#operators).
+* `const_new` adds const constructors, see [Const](#const).
+* `iter` forwards iteration traits, see [Iteration](#iteration).
+* `index` forwards [Index](std::ops::Index) and [IndexMut](std::ops::IndexMut), see [Indexing](#indexing).
+* `future` implements [Future](std::future::Future) by polling the payload, see [Futures](#futures).
+* `io` forwards the [std::io] traits, see [I/O](#io).
+
+By default, inherent conversion functions like `from_arc` and `from_result` have the same visibility as the field.  You can
+set their visibility separately with `conversions`.  This lets other modules convert without also seeing the field:
+
+```
+mod platform {
+    pub struct Widget;
+}
+mod wrapper {
+    use boil::boil;
+    #[boil(conversions = pub(crate))]
+    pub struct Widget(super::platform::Widget);
+}
+# fn main() {
+let w = std::sync::Arc::new(platform::Widget);
+let w: std::sync::Arc<wrapper::Widget> = wrapper::Widget::from_arc(w);
+# }
+```
+
+```
+# use boil::boil;
+#[boil(no_deref, no_projections)]
+struct Foo(u8);
+let f: Foo = 2.into();
+let g: &u8 = f.as_ref();
+```
+
+Unknown arguments are an error, so a typo doesn't silently fall back to the defaults.
+
+```compile_fail
+# use boil::boil;
+#[boil(scope)]
+struct Foo(u8);
+```
+
 # Features
 
 `boil` should support all standard Rust syntax to declare types, including generics, associated types, where clauses, paths, and more.
@@ -337,6 +628,29 @@ mod imp {
 #[boil]
 struct Example<'a, 'b, C, D: std::fmt::Debug> (imp::Example<'a, 'b, C, D>)  where 'b: 'a, C: imp::AssociatedType + Sync, D: ;
 ```
+
+Syntax `boil` doesn't understand is reported as a compile error on the offending token, rather than a panic.  For example,
+a wrapper must be a tuple struct with exactly one field:
+
+```compile_fail
+# use boil::boil;
+#[boil]
+struct Pair(u8, u16); //error: boil wraps a single field, but this tuple struct has more than one
+```
+
+Const generic parameters and attributes on the field aren't supported either:
+
+```compile_fail
+# use boil::boil;
+#[boil]
+struct Block<const N: usize>([u8; N]); //error: Const generic parameters are not supported
+```
+
+```compile_fail
+# use boil::boil;
+#[boil]
+struct Documented(#[doc = "the payload"] u8); //error: Attributes on the field are not supported
+```
 */
 pub use procmacro::boil;
 
@@ -390,6 +704,8 @@ The following traits are implemented like [boil]
 * [std::pin::Pin] projections
 * [std::sync::Arc]/[std::rc::Rc] projections
 
+It also accepts the same [arguments](boil#arguments).
+
 ## Trait assumptions
 
 As a detail, in cases where we are converting to the payload type, `boil_unsized`
@@ -454,6 +770,26 @@ on owned types due to the size restrictions, so they are implemented on `&Wrappe
 
 In addition, `from_result_mut` and `into_result_mut` are implemented for the respective conversions of `&mut Wrapped` and `&mut Payload`.
 
+# Arguments
+
+`boil_unsized` accepts the same arguments as [boil], except those that need to move the payload by value:
+`ops`, `const_new` and `iter`.  For example, `index` forwards to a trait object's Index supertrait,
+and `future` and `io` make wrappers over `dyn Future` or `dyn Read` futures and readers too:
+
+```
+use boil::boil_unsized;
+trait Bytes: std::ops::Index<usize, Output = u8> {}
+impl Bytes for Vec<u8> {}
+#[boil_unsized(index)]
+struct ErasedBytes(dyn Bytes);
+# fn main() {
+let v = vec![1u8, 2, 3];
+let e: &dyn Bytes = &v;
+let b: &ErasedBytes = e.into();
+assert_eq!(b[1], 2);
+# }
+```
+
 
 
 
@@ -463,30 +799,391 @@ In addition, `from_result_mut` and `into_result_mut` are implemented for the res
 pub use procmacro::boil_unsized;
 
 /**
-Derives [Display] for a type declared with [boil].
+Derives [Display](std::fmt::Display) for a type declared with [boil].
 
-The payload must implement `Display`.
+By default, the wrapper displays exactly like its payload, which must implement `Display`.
 
 ```
 use boil::boil;
 #[boil]
 #[derive(boil::Display)]
 struct Display(u8);
+```
+
+To add context, give a format string with `#[display("...")]`.  The payload is the format string's only argument, so it
+can be displayed with `{}` or debug-formatted with `{:?}`.
+
+```
+use boil::boil;
+#[boil]
+#[derive(boil::Display)]
+#[display("widget#{}")]
+struct WidgetId(u32);
+assert_eq!(WidgetId(3).to_string(), "widget#3");
+```
+
+The payload is bound by the formatting traits its placeholders use, like `Display` for `{}`, `Debug` for `{:?}` or
+`LowerHex` for `{:x}`, so a generic wrapper implements `Display` wherever its payload can be formatted.
+
+```
+use boil::boil;
+#[boil]
+#[derive(boil::Display)]
+#[display("widget#{:x}")]
+struct WidgetKey<K>(std::num::Wrapping<K>);
+assert_eq!(WidgetKey(std::num::Wrapping(255u8)).to_string(), "widget#ff");
+```
+
+To display the payload's [Debug](std::fmt::Debug) output instead, use `#[display(debug)]`.
+
+```
+use boil::boil;
+#[boil]
+#[derive(boil::Display)]
+#[display(debug)]
+struct Name(&'static str);
+assert_eq!(Name("widget").to_string(), "\"widget\"");
+```
 */
 pub use procmacro::Display;
 
 /**
-Derives [Error] for a type declared with [boil].
+Derives [LowerHex](std::fmt::LowerHex) for a type declared with [boil] or [boil_unsized].
 
-The payload must implement [Error].
+The payload must implement `LowerHex`.  Flags like `{:#x}` are passed through to the payload.
 
+```
 use boil::boil;
 #[boil]
-#[derive(boil::Display,boil::Error)]
+#[derive(boil::LowerHex, boil::UpperHex, boil::Octal, boil::Binary)]
+struct Handle(u64);
+assert_eq!(format!("{:#x}", Handle(255)), "0xff");
+assert_eq!(format!("{:X}", Handle(255)), "FF");
+assert_eq!(format!("{:o}", Handle(8)), "10");
+assert_eq!(format!("{:08b}", Handle(5)), "00000101");
+```
+*/
+pub use procmacro::LowerHex;
+
+/**
+Derives [UpperHex](std::fmt::UpperHex) for a type declared with [boil] or [boil_unsized].
+
+The payload must implement `UpperHex`.  See [LowerHex](macro@LowerHex).
+*/
+pub use procmacro::UpperHex;
+
+/**
+Derives [Octal](std::fmt::Octal) for a type declared with [boil] or [boil_unsized].
+
+The payload must implement `Octal`.  See [LowerHex](macro@LowerHex).
+*/
+pub use procmacro::Octal;
+
+/**
+Derives [Binary](std::fmt::Binary) for a type declared with [boil] or [boil_unsized].
+
+The payload must implement `Binary`.  See [LowerHex](macro@LowerHex).
+*/
+pub use procmacro::Binary;
+
+/**
+Derives [LowerExp](std::fmt::LowerExp) for a type declared with [boil] or [boil_unsized].
+
+The payload must implement `LowerExp`.
+
+```
+use boil::boil;
+#[boil]
+#[derive(boil::LowerExp, boil::UpperExp)]
+struct Meters(f64);
+assert_eq!(format!("{:e}", Meters(1500.0)), "1.5e3");
+assert_eq!(format!("{:E}", Meters(1500.0)), "1.5E3");
+```
+*/
+pub use procmacro::LowerExp;
+
+/**
+Derives [UpperExp](std::fmt::UpperExp) for a type declared with [boil] or [boil_unsized].
+
+The payload must implement `UpperExp`.  See [LowerExp](macro@LowerExp).
+*/
+pub use procmacro::UpperExp;
+
+/**
+Derives [Pointer](std::fmt::Pointer) for a type declared with [boil] or [boil_unsized].
+
+The payload must implement `Pointer`, so it's usually a reference or pointer.
+
+```
+use boil::boil;
+#[boil]
+#[derive(boil::Pointer)]
+struct Raw(*const u8);
+let r = Raw(std::ptr::null());
+assert_eq!(format!("{:p}", r), "0x0");
+```
+*/
+pub use procmacro::Pointer;
+
+/**
+Derives [Debug](std::fmt::Debug) for a type declared with [boil] or [boil_unsized].
+
+The wrapper formats exactly like its payload, which must implement `Debug`.
+
+```
+use boil::boil;
+#[boil]
+#[derive(boil::Debug)]
+struct Widget(u8);
+assert_eq!(format!("{:?}", Widget(2)), "2");
+```
+
+To prefix the output with the wrapper's name, use `#[debug(prefix)]`, or `#[debug(prefix = "Name")]` for a different name.
+
+```
+# use boil::boil;
+#[boil]
+#[derive(boil::Debug)]
+#[debug(prefix)]
+struct Widget(u8);
+assert_eq!(format!("{:?}", Widget(2)), "Widget(2)");
+```
+
+For [boil_unsized], the payload's trait must have `Debug` as a supertrait.
+
+```
+use boil::boil_unsized;
+trait Erased: std::fmt::Debug {}
+#[boil_unsized]
+#[derive(boil::Debug)]
+struct Wrap(dyn Erased);
+```
+*/
+pub use procmacro::Debug;
+
+/**
+Derives [Default] for a type declared with [boil], using the payload's default.
+
+```
+use boil::boil;
+#[boil]
+#[derive(boil::Default)]
+struct Count(u32);
+assert_eq!(*Count::default(), 0);
+```
+*/
+pub use procmacro::Default;
+
+/**
+Derives [Clone] for a type declared with [boil], by cloning the payload.
+
+Unlike `#[derive(Clone)]`, the impl is bounded on the payload being `Clone` rather than on each type parameter.
+
+```
+use boil::boil;
+struct NotClone;
+#[boil]
+#[derive(boil::Clone)]
+struct Handle<G>(std::sync::Arc<G>);
+let h = Handle(std::sync::Arc::new(NotClone));
+let _ = h.clone();
+```
+*/
+pub use procmacro::Clone;
+
+/**
+Derives [Copy] for a type declared with [boil], wherever the payload is `Copy`.
+
+Pair it with [Clone](macro@Clone), since `#[derive(Clone)]` from std would require each type parameter to be `Clone`.
+
+```
+use boil::boil;
+#[boil]
+#[derive(boil::Clone, boil::Copy)]
+struct Marker<G>(std::marker::PhantomData<G>);
+struct NotCopy;
+let m: Marker<NotCopy> = Marker(std::marker::PhantomData);
+let n = m;
+let _ = (m, n);
+```
+*/
+pub use procmacro::Copy;
+
+/**
+Derives [PartialEq] for a type declared with [boil], by comparing the payloads.
+
+The payload must implement `PartialEq`.  Besides comparing wrappers with each other, this also implements `PartialEq`
+between the wrapper and the payload in both directions.
+
+```
+use boil::boil;
+#[boil]
+#[derive(boil::PartialEq)]
+struct WidgetId(u32);
+assert!(WidgetId(2) == WidgetId(2));
+assert!(WidgetId(2) == 2);
+assert!(2 == WidgetId(2));
+```
+*/
+pub use procmacro::PartialEq;
+
+/**
+Derives [Eq] for a type declared with [boil].  The payload must implement `Eq`.
+
+See also [PartialEq](macro@PartialEq).
+*/
+pub use procmacro::Eq;
+
+/**
+Derives [PartialOrd] for a type declared with [boil], by comparing the payloads.
+
+The payload must implement `PartialOrd`.  Like [PartialEq](macro@PartialEq), this also compares the wrapper and the
+payload in both directions.
+
+```
+use boil::boil;
+#[boil]
+#[derive(boil::PartialEq, boil::PartialOrd)]
+struct Millis(u64);
+assert!(Millis(2) < Millis(3));
+assert!(Millis(2) < 3);
+assert!(1 < Millis(2));
+```
+*/
+pub use procmacro::PartialOrd;
+
+/**
+Derives [Ord] for a type declared with [boil].  The payload must implement `Ord`.
+*/
+pub use procmacro::Ord;
+
+/**
+Derives [Hash](std::hash::Hash) for a type declared with [boil].
+
+The wrapper hashes exactly like its payload, which must implement `Hash`.  Since boiled types implement
+[Borrow](std::borrow::Borrow) of their payload, this keeps the contract of `Borrow`, together with [Eq](macro@Eq)
+and [PartialEq](macro@PartialEq).  So collections of wrappers can be queried by payload:
+
+```
+use boil::boil;
+use std::collections::HashSet;
+#[boil]
+#[derive(boil::PartialEq, boil::Eq, boil::Hash)]
+struct WidgetId(u32);
+
+let mut set = HashSet::new();
+set.insert(WidgetId(2));
+assert!(set.contains(&2));
+```
+*/
+pub use procmacro::Hash;
+
+/**
+Derives [FromStr](std::str::FromStr) for a type declared with [boil], by parsing the payload.
+
+The payload must implement `FromStr`.  By default, the error is the payload's error, unchanged.
+
+```
+use boil::boil;
+#[boil]
+#[derive(boil::FromStr)]
+struct Port(u16);
+let p: Port = "8080".parse().unwrap();
+let e: Result<Port, std::num::ParseIntError> = "http".parse();
+```
+
+To use a different error, such as a boiled one, use `#[from_str(error = Type)]`.  The payload's error is converted with
+[Into], like the `from_result` conversion.
+
+```
+use boil::{boil,Display,Error};
+#[boil]
+#[derive(Debug,Display,Error)]
+struct ParseError(std::num::ParseIntError);
+
+#[boil]
+#[derive(boil::FromStr)]
+#[from_str(error = ParseError)]
+struct Port(u16);
+
+let e: Result<Port, ParseError> = "http".parse();
+```
+*/
+pub use procmacro::FromStr;
+
+/**
+Derives [Error](std::error::Error) for a type declared with [boil].
+
+The payload must implement `Error`.  By default the wrapper is transparent: it displays like the payload (with
+[Display](macro@Display)), and its `source()` is the payload's `source()`.
+
+```
+use boil::boil;
+#[boil]
+#[derive(Debug,boil::Display,boil::Error)]
 struct Display(std::convert::Infallible);
+```
+
+To add a layer to the error chain instead, give the wrapper its own message with `#[error("...")]`.  This implements
+`Display` with that message, like [Display](macro@Display)'s format strings, and returns the payload itself as
+`source()`.  So chain printers show both the wrapper and the payload.
+
+```
+use boil::boil;
+use std::error::Error;
+#[boil]
+#[derive(Debug,boil::Error)]
+#[error("couldn't read the widget configuration")]
+struct ConfigError(std::io::Error);
+
+let e = ConfigError(std::io::Error::other("file not found"));
+assert_eq!(e.to_string(), "couldn't read the widget configuration");
+assert_eq!(e.source().unwrap().to_string(), "file not found");
+```
+
+If you'd rather implement `Display` separately, `#[error(source)]` returns the payload as `source()` without implementing
+`Display`.
+
+The deprecated `description()` and `cause()` are not implemented, so they have their default behavior.
 */
 pub use procmacro::Error;
 
+/**
+Declares named presets of [boil] arguments, so a recurring shape of newtype can be declared once and reused.
+
+Each preset is `name = arguments;`, where the arguments are anything [boil] accepts.  Apply a preset with
+`#[boil(preset = name)]`; any other arguments are applied after the preset's.
+
+```
+mod presets {
+    boil::preset! {
+        ///Copyable identifiers that can't be changed through `DerefMut`
+        pub(crate) id = no_deref_mut, derive(Clone, Copy, PartialEq, Eq, Hash, Debug);
+    }
+}
+
+use boil::boil;
+#[boil(preset = presets::id)]
+struct WidgetId(u32);
+
+# fn main() {
+let a = WidgetId(1);
+let b = a;
+assert_eq!(a, b);
+# }
+```
+
+Presets expand to a `macro_rules!` macro of the same name, so they follow macro scoping rules.  Presets declared without
+a visibility can be used later in the same module, and a restricted visibility like `pub(crate)` lets them be used by path.
+Presets can't be `pub`, since a macro declared by another macro can't be exported by path.
+
+A preset can include other presets with `preset = other`.  The other preset's path is resolved where the outer preset is
+applied, so an absolute path like `crate::presets::other` is most reliable.
+
+Presets refer to boil as `::boil`, so they don't work if the dependency is renamed in `Cargo.toml`.
+*/
+pub use procmacro::preset;
+
 ///This example [boil]ed type shows the traits and functions that are implemented by calling [boil].
 ///
 ///This struct is not real API, but appears in the documentation as an example.
@@ -1,4 +1,4 @@
-use boil::boil;
+use boil::{boil,boil_unsized};
 use std::fmt::Debug;
 use crate::imp::AssociatedType;
 
@@ -109,4 +109,502 @@ pub(crate) struct CratePrivateStruct;
 #[boil(scoped)]
 pub struct CratePrivateWrap(pub(crate) CratePrivateStruct);
 #[boil::boil_unsized(scoped)]
-pub struct TraitPrivatewrap(pub(crate) dyn CratePrivateTrait);
\ No newline at end of file
+pub struct TraitPrivatewrap(pub(crate) dyn CratePrivateTrait);
+#[boil(no_deref, no_borrow)]
+struct NoDeref(u8);
+#[allow(unused)]
+fn no_deref() {
+    let n: NoDeref = 2.into();
+    let _f: &u8 = n.as_ref();
+}
+#[allow(unused)]
+#[boil(no_as_ref, no_from, no_projections)]
+struct DerefOnly(u8);
+#[allow(unused)]
+#[boil_unsized(no_from, no_deref)]
+struct UnsizedNoFrom(dyn imp::AssociatedType<A=u8>);
+
+mod conversions {
+    use boil::{boil,boil_unsized};
+    #[boil(conversions = pub(crate))]
+    pub struct Widget(super::imp::Foo);
+    #[boil_unsized(conversions = pub)]
+    pub struct Erased(dyn Send);
+}
+#[allow(unused)]
+fn conversions() {
+    let w = conversions::Widget::from_rc(std::rc::Rc::new(imp::Foo));
+    let _: std::rc::Rc<imp::Foo> = w.to_rc();
+    let _: Result<conversions::Widget, ()> = conversions::Widget::from_result(Ok::<_, ()>(imp::Foo));
+}
+
+#[allow(unused)]
+#[boil]
+struct TrailingComma(u8,);
+
+mod presets {
+    boil::preset! {
+        ///Copyable identifiers
+        pub(crate) id = no_deref_mut, derive(Clone, Copy, PartialEq, Eq, Hash, Debug);
+        pub(crate) handle = scoped, preset = crate::presets::id;
+        local = no_from;
+        empty = ;
+    }
+    #[allow(unused)]
+    #[boil::boil(preset = local)]
+    struct Local(u8);
+    #[allow(unused)]
+    #[boil::boil(preset = empty)]
+    struct Empty(u8);
+    #[allow(unused)]
+    #[boil::boil(preset = empty, no_from)]
+    struct EmptyWithArgs(u8);
+}
+#[boil(preset = presets::id)]
+struct Id(u32);
+#[boil(preset = presets::handle, conversions = pub(crate))]
+pub struct Handle(u64);
+#[test]
+fn presets() {
+    let a = Id(1);
+    let b = a;
+    assert_eq!(a, b);
+    let _: u32 = *a;
+    let _ = Handle::from_rc(std::rc::Rc::new(3));
+}
+
+#[boil]
+#[derive(boil::Debug)]
+#[debug(prefix = "Id")]
+struct DebugPrefix(imp::Foo);
+trait DebugErased: Debug {}
+impl DebugErased for imp::Foo {}
+#[boil_unsized]
+#[derive(boil::Debug)]
+#[debug(prefix)]
+struct DebugUnsized(dyn DebugErased);
+#[test]
+fn debug() {
+    assert_eq!(format!("{:?}", DebugPrefix(imp::Foo)), "Id(Foo)");
+    let e: &dyn DebugErased = &imp::Foo;
+    let w: &DebugUnsized = e.into();
+    assert_eq!(format!("{:?}", w), "DebugUnsized(Foo)");
+}
+
+#[boil]
+#[derive(boil::PartialEq, boil::Eq, boil::PartialOrd, boil::Ord, boil::Hash)]
+struct Compare(u32);
+#[boil]
+#[derive(boil::PartialEq, boil::PartialOrd)]
+struct CompareG<G: PartialOrd>(Vec<G>);
+#[test]
+fn compare() {
+    use std::collections::BTreeSet;
+    assert!(Compare(1) < Compare(2));
+    assert!(Compare(1) == 1);
+    assert!(1 == Compare(1));
+    let set: BTreeSet<Compare> = [Compare(1)].into_iter().collect();
+    assert!(set.contains(&1));
+    assert!(CompareG(vec![1.0]) < vec![2.0]);
+}
+
+#[boil(ops(Add, Sub, Mul, Div, Rem, BitAnd, BitOr, BitXor, Shl, Shr, Neg, Not, mixed))]
+struct Ops(i64);
+#[boil(ops(Add, mixed))]
+struct OpsG<G>(std::num::Wrapping<G>) where G: Clone,;
+#[allow(unused)]
+fn ops() {
+    let mut o = Ops(1) + Ops(2) - Ops(3) * Ops(4) / Ops(5) % Ops(6);
+    o = (o & Ops(1)) | Ops(2) ^ Ops(3);
+    o = -!(o << Ops(1) >> Ops(2));
+    o += 1;
+    o <<= Ops(2);
+    let _: OpsG<u8> = OpsG(std::num::Wrapping(1)) + std::num::Wrapping(2);
+}
+
+#[boil]
+#[derive(boil::FromStr)]
+struct Parsed(u16);
+#[boil]
+#[derive(Debug, boil::Display, boil::Error)]
+struct ParsedError(std::num::ParseIntError);
+#[boil]
+#[derive(boil::FromStr)]
+#[from_str(error = ParsedError)]
+struct ParsedInto(u8);
+#[allow(unused)]
+fn from_str() {
+    let _: Parsed = "2".parse().unwrap();
+    let _: Result<ParsedInto, ParsedError> = "x".parse();
+}
+
+#[boil]
+#[derive(boil::LowerHex, boil::UpperHex, boil::Octal, boil::Binary)]
+struct FmtInt(u64);
+#[boil]
+#[derive(boil::LowerExp, boil::UpperExp)]
+struct FmtFloat(f32);
+#[boil]
+#[derive(boil::Pointer)]
+struct FmtPointer<'a>(&'a u8);
+trait HexErased: std::fmt::LowerHex {}
+#[boil_unsized]
+#[derive(boil::LowerHex)]
+struct FmtUnsized(dyn HexErased);
+#[allow(unused)]
+fn fmt() {
+    let _ = format!("{:#x}{:X}{:o}{:b}", FmtInt(1), FmtInt(2), FmtInt(3), FmtInt(4));
+    let _ = format!("{:e}{:E}{:p}", FmtFloat(1.0), FmtFloat(2.0), FmtPointer(&1));
+}
+
+#[boil]
+#[derive(Debug, boil::Display, boil::Error)]
+#[display("timed out: {:?}")]
+struct DisplayFormat(std::sync::mpsc::RecvTimeoutError);
+#[boil]
+#[derive(boil::Display)]
+#[display("{{no payload}}")]
+struct DisplayLiteral(u8);
+#[boil]
+#[derive(boil::Display)]
+#[display(r#"widget "{}""#)]
+struct DisplayRaw(u8);
+#[boil]
+#[derive(boil::Display)]
+#[display(debug)]
+struct DisplayDebug(imp::Foo);
+#[boil]
+#[derive(boil::Display)]
+#[display("widget#{}")]
+struct DisplayFormatG<G>(imp::FooG<G>);
+#[boil]
+#[derive(Debug, boil::Error)]
+#[error("{:?} failed")]
+struct ErrorMessageG<G: Debug>(std::sync::mpsc::SendError<G>);
+#[test]
+fn display_format() {
+    assert_eq!(DisplayLiteral(1).to_string(), "{no payload}");
+    assert_eq!(DisplayRaw(1).to_string(), "widget \"1\"");
+    assert_eq!(DisplayDebug(imp::Foo).to_string(), "Foo");
+}
+
+#[boil]
+#[derive(Debug, boil::Error)]
+#[error("receive failed: {}")]
+struct ErrorMessage(std::sync::mpsc::RecvTimeoutError);
+#[boil]
+#[derive(Debug, boil::Display, boil::Error)]
+#[display("context")]
+#[error(source)]
+struct ErrorSource(std::sync::mpsc::RecvTimeoutError);
+#[test]
+fn error_source() {
+    use std::error::Error;
+    let e = ErrorMessage(std::sync::mpsc::RecvTimeoutError::Timeout);
+    assert!(e.source().is_some());
+    let e = ErrorSource(std::sync::mpsc::RecvTimeoutError::Timeout);
+    assert!(e.source().is_some());
+}
+
+#[boil(const_new)]
+#[derive(boil::Default)]
+struct ConstNew(u64);
+#[boil(const_new)]
+#[derive(boil::Default)]
+struct ConstNewG<G>(Vec<G>);
+#[allow(unused)]
+static CONST_NEW: ConstNew = ConstNew::new(3);
+const CONST_NEW_G: ConstNewG<u8> = ConstNewG::new(Vec::new());
+#[allow(unused)]
+fn const_new() {
+    let _: u64 = ConstNew::default().into_inner();
+    let _: Vec<u8> = CONST_NEW_G.into_inner();
+}
+
+#[boil(iter)]
+struct Iter(Vec<u8>);
+#[boil(iter)]
+struct IterG<K: std::hash::Hash + Eq, V>(std::collections::HashMap<K, V>);
+#[boil(iter)]
+struct IterLifetime<'a, V>(std::slice::Iter<'a, V>);
+#[boil(iter)]
+struct Route(std::path::PathBuf);
+#[allow(unused)]
+fn iter() {
+    let mut i: Iter = vec![1, 2].into_iter().collect();
+    i.extend([3]);
+    for _ in &i {}
+    for _ in &mut i {}
+    for _ in i {}
+    let g: IterG<u8, u8> = [(1, 2)].into_iter().collect();
+    for (_k, _v) in &g {}
+    for _ in IterLifetime([1u8].iter()) {}
+    let r: Route = ["a", "b"].into_iter().collect();
+    for _ in &r {}
+}
+
+#[boil(scoped, index)]
+pub struct Index(std::collections::HashMap<String, u8>);
+#[boil(index)]
+struct IndexG<G>(Vec<G>);
+trait Indexed: std::ops::IndexMut<usize, Output = u8> {}
+impl Indexed for Vec<u8> {}
+#[boil_unsized(index)]
+struct IndexUnsized(dyn Indexed);
+#[test]
+fn index() {
+    let i: Index = std::collections::HashMap::from([("a".to_string(), 1)]).into();
+    let _: u8 = i["a"];
+    let mut g = IndexG(vec![1, 2]);
+    g[0] = 3;
+    let _: &[u8] = &g[..1];
+    let mut v = vec![1u8];
+    let s: &mut IndexUnsized = (&mut v as &mut dyn Indexed).into();
+    s[0] = 2;
+    assert_eq!(v, [2]);
+}
+
+#[boil(future)]
+struct Future(std::future::Ready<u8>);
+#[boil(future(output = Compare))]
+struct FutureInto<G>(std::future::Ready<G>);
+#[boil_unsized(future(output = Option<u8>))]
+struct FutureUnsized(dyn std::future::Future<Output = u8>);
+#[allow(unused)]
+fn future() {
+    fn assert_future<F: std::future::Future<Output = O>, O>(_: F) {}
+    assert_future::<_, u8>(Future(std::future::ready(1)));
+    assert_future::<_, Compare>(FutureInto(std::future::ready(1u32)));
+    fn unsized_future(p: std::pin::Pin<&mut FutureUnsized>) {
+        assert_future::<_, Option<u8>>(p);
+    }
+}
+
+#[boil(io)]
+struct Io(std::fs::File);
+#[boil(io)]
+struct IoG<G>(std::io::Cursor<G>);
+#[boil(io)]
+struct IoBuf(std::io::BufReader<Io>);
+trait Stream: std::io::Read + std::io::Write {}
+#[boil_unsized(io)]
+struct IoUnsized(dyn Stream);
+#[allow(unused)]
+fn io() {
+    use std::io::{BufRead, Read, Seek, Write};
+    let mut f = Io(std::fs::File::open("/dev/null").unwrap());
+    let _ = f.read_to_end(&mut Vec::new());
+    let _ = (&f).write_all(b"a");
+    let _ = (&f).rewind();
+    let mut c = IoG(std::io::Cursor::new(vec![1u8]));
+    let _ = c.read_line(&mut String::new());
+    let _ = c.write(&[1]);
+    let mut b = IoBuf(std::io::BufReader::new(f));
+    let _ = b.skip_until(b'\n');
+    let _ = b.seek_relative(1);
+    fn unsized_io(s: &mut IoUnsized) {
+        let _ = s.read_exact(&mut [0]);
+        let _ = s.flush();
+    }
+}
+
+//the last parameter's bound has generic arguments of its own
+trait NestedDyn<G> {}
+#[allow(unused)]
+#[boil_unsized(index, io)]
+struct NestedUnsized<G: AssociatedType<A = u8>>(dyn NestedDyn<G>);
+#[allow(unused)]
+#[boil(index, iter)]
+struct NestedIter<G: AssociatedType<A = u8>>(Vec<G>);
+#[allow(unused)]
+#[boil(io)]
+struct NestedIo<G: AssociatedType<A = u8>>(std::io::Cursor<Vec<G>>);
+
+struct NotDerived;
+#[boil]
+#[derive(boil::Clone, boil::Copy, boil::Debug, boil::PartialEq, boil::Eq, boil::Hash, boil::Default)]
+struct Bounded<G>(std::marker::PhantomData<G>);
+#[boil]
+#[derive(boil::Display)]
+struct BoundedDisplay<G>(Vec<G>);
+#[boil]
+#[derive(Debug, boil::Display, boil::Error)]
+#[error(source)]
+struct BoundedError<G: Debug>(std::sync::mpsc::SendError<G>);
+#[test]
+fn bounded() {
+    let b: Bounded<NotDerived> = Bounded::default();
+    let c = b;
+    assert_eq!(b, c);
+    let _ = format!("{:?}", b);
+}
+
+#[test]
+fn slices() {
+    let mut raw = [1u32, 2];
+    let wrapped: &[Compare] = Compare::from_slice(&raw);
+    assert_eq!((wrapped.as_ptr().cast::<u32>(), wrapped.len()), (raw.as_ptr(), 2));
+    assert!(*wrapped == [Compare(1), Compare(2)]);
+    let wrapped: &mut [Compare] = Compare::from_slice_mut(&mut raw);
+    wrapped[0] = Compare(3);
+    let inner: &mut [u32] = Compare::as_inner_slice_mut(wrapped);
+    inner[1] = 4;
+    assert_eq!(raw, [3, 4]);
+    let g: &[FooG<u8>] = FooG::from_slice(&[]);
+    let _: &[imp::FooG<u8>] = FooG::as_inner_slice(g);
+}
+
+#[test]
+fn collections() {
+    let v = vec![1u32, 2, 3];
+    let (ptr, len, capacity) = (v.as_ptr(), v.len(), v.capacity());
+    let v: Vec<Compare> = Compare::from_vec(v);
+    assert_eq!((v.as_ptr().cast::<u32>(), v.len(), v.capacity()), (ptr, len, capacity));
+    assert!(v == [Compare(1), Compare(2), Compare(3)]);
+    let v: Vec<u32> = Compare::into_inner_vec(v);
+    assert_eq!((v.as_ptr(), v.len(), v.capacity()), (ptr, len, capacity));
+    assert_eq!(v, [1, 2, 3]);
+
+    let b = v.into_boxed_slice();
+    let ptr = b.as_ptr();
+    let b: Box<[Compare]> = Compare::from_boxed_slice(b);
+    assert_eq!((b.as_ptr().cast::<u32>(), b.len()), (ptr, 3));
+    assert!(*b == [Compare(1), Compare(2), Compare(3)]);
+    let b: Box<[u32]> = Compare::into_inner_boxed_slice(b);
+    assert_eq!((b.as_ptr(), &*b), (ptr, &[1, 2, 3][..]));
+
+    let a: std::sync::Arc<[u32]> = b.into();
+    let ptr = a.as_ptr();
+    let a: std::sync::Arc<[Compare]> = Compare::from_arc_slice(a);
+    assert_eq!((a.as_ptr().cast::<u32>(), a.len()), (ptr, 3));
+    assert!(*a == [Compare(1), Compare(2), Compare(3)]);
+    let a: std::sync::Arc<[u32]> = Compare::into_inner_arc_slice(a);
+    assert_eq!((a.as_ptr(), &*a), (ptr, &[1, 2, 3][..]));
+
+    let r: std::rc::Rc<[u32]> = std::rc::Rc::from(&*a);
+    let ptr = r.as_ptr();
+    let r: std::rc::Rc<[Compare]> = Compare::from_rc_slice(r);
+    assert_eq!((r.as_ptr().cast::<u32>(), r.len()), (ptr, 3));
+    assert!(*r == [Compare(1), Compare(2), Compare(3)]);
+    let r: std::rc::Rc<[u32]> = Compare::into_inner_rc_slice(r);
+    assert_eq!((r.as_ptr(), &*r), (ptr, &[1, 2, 3][..]));
+}
+
+#[allow(unused)]
+fn options() {
+    let _: Option<Foo> = Foo::from_option(Some(imp::Foo));
+    let _: Option<imp::Foo> = Foo::into_option(None);
+    let mut raw = imp::Foo;
+    let _: Option<&Foo> = Foo::from_option_ref(Some(&raw));
+    let w: Option<&mut Foo> = Foo::from_option_mut(Some(&mut raw));
+    let _: Option<&mut imp::Foo> = Foo::into_option_mut(w);
+    let b: Option<Box<FooLifetime>> = FooLifetime::from_option_box(None);
+    let _: Option<Box<imp::FooLifetime>> = FooLifetime::into_option_box(b);
+}
+
+#[allow(unused)]
+fn result_refs() {
+    #[boil]
+    #[derive(Debug, boil::Display, boil::Error)]
+    struct RefError(std::convert::Infallible);
+    let raw = imp::Foo;
+    let r: Result<&Foo, RefError> = Foo::from_result_ref(Ok::<_, std::convert::Infallible>(&raw));
+    let _: Result<&imp::Foo, std::convert::Infallible> = Foo::into_result_ref(r);
+    let mut raw = vec![1u8];
+    let r: Result<&mut CompareG<u8>, ()> = CompareG::from_result_mut(Ok::<_, ()>(&mut raw));
+    let _: Result<&mut Vec<u8>, ()> = CompareG::into_result_mut(r);
+}
+
+#[allow(unused)]
+fn pin_pointers() {
+    use std::pin::Pin;
+    let b: Pin<Box<Foo>> = Box::pin(imp::Foo).into();
+    let _: Pin<Box<imp::Foo>> = b.into();
+    let a: Pin<std::sync::Arc<CompareG<u8>>> = CompareG::from_pin_arc(std::sync::Arc::pin(vec![1u8]));
+    let _: Pin<std::sync::Arc<Vec<u8>>> = a.to_pin_arc();
+    let r: Pin<std::rc::Rc<Foo>> = Foo::from_pin_rc(std::rc::Rc::pin(imp::Foo));
+    let _: Pin<std::rc::Rc<imp::Foo>> = r.to_pin_rc();
+    let erased: Pin<Box<dyn std::future::Future<Output = u8>>> = Box::pin(std::future::ready(1));
+    let w: Pin<Box<FutureUnsized>> = erased.into();
+    let erased: Pin<Box<dyn std::future::Future<Output = u8>>> = w.into();
+    drop(erased);
+    fn unsized_rc(r: Pin<std::rc::Rc<dyn Stream>>) -> Pin<std::rc::Rc<dyn Stream>> {
+        IoUnsized::from_pin_rc(r).to_pin_rc()
+    }
+}
+
+#[test]
+fn weak() {
+    let dangling: std::sync::Weak<Foo> = Foo::from_weak_arc(std::sync::Weak::new());
+    assert!(Foo::to_weak_arc(dangling).upgrade().is_none());
+    let strong = std::sync::Arc::new(1u32);
+    let w: std::sync::Weak<Compare> = Compare::from_weak_arc(std::sync::Arc::downgrade(&strong));
+    assert_eq!(w.as_ptr().cast::<u32>(), std::sync::Arc::as_ptr(&strong));
+    assert!(*w.upgrade().unwrap() == Compare(1));
+    let w: std::sync::Weak<u32> = Compare::to_weak_arc(w);
+    assert_eq!((w.as_ptr(), w.strong_count(), w.weak_count()), (std::sync::Arc::as_ptr(&strong), 1, 1));
+    let strong = std::rc::Rc::new(vec![1u8]);
+    let w: std::rc::Weak<CompareG<u8>> = CompareG::from_weak_rc(std::rc::Rc::downgrade(&strong));
+    assert!(*w.upgrade().unwrap() == vec![1]);
+    let w: std::rc::Weak<Vec<u8>> = CompareG::to_weak_rc(w);
+    assert_eq!(w.as_ptr(), std::rc::Rc::as_ptr(&strong));
+    drop(strong);
+    assert!(w.upgrade().is_none());
+    let strong: std::rc::Rc<dyn Indexed> = std::rc::Rc::new(vec![1u8]);
+    let w: std::rc::Weak<IndexUnsized> = IndexUnsized::from_weak_rc(std::rc::Rc::downgrade(&strong));
+    assert_eq!(w.upgrade().unwrap()[0], 1);
+    let w: std::rc::Weak<dyn Indexed> = IndexUnsized::to_weak_rc(w);
+    assert!(std::ptr::addr_eq(w.as_ptr(), std::rc::Rc::as_ptr(&strong)));
+}
+
+#[test]
+fn cells() {
+    use std::mem::{ManuallyDrop, MaybeUninit};
+    let cell = std::cell::Cell::new(1u32);
+    let wrapped: &std::cell::Cell<Compare> = Compare::from_cell(&cell);
+    wrapped.set(Compare(2));
+    assert_eq!(cell.get(), 2);
+    assert_eq!(Compare::as_inner_cell(wrapped).as_ptr(), cell.as_ptr());
+    let cell = std::cell::UnsafeCell::new(vec![1u8]);
+    let wrapped: &std::cell::UnsafeCell<CompareG<u8>> = CompareG::from_unsafe_cell(&cell);
+    assert_eq!(CompareG::as_inner_unsafe_cell(wrapped).get(), cell.get());
+
+    let m: ManuallyDrop<CompareG<u8>> = CompareG::from_manually_drop(ManuallyDrop::new(vec![1, 2]));
+    assert!(*m == vec![1, 2]);
+    let mut m: ManuallyDrop<Vec<u8>> = CompareG::into_inner_manually_drop(m);
+    assert_eq!(*m, [1, 2]);
+    //safe because `m` isn't used again
+    unsafe { ManuallyDrop::drop(&mut m) };
+
+    let mut u: MaybeUninit<Compare> = Compare::from_maybe_uninit(MaybeUninit::uninit());
+    Compare::as_inner_maybe_uninit_mut(&mut u).write(3);
+    let u: MaybeUninit<u32> = Compare::into_inner_maybe_uninit(u);
+    //safe because it was written above
+    assert_eq!(unsafe { u.assume_init() }, 3);
+    let buffer = [MaybeUninit::new(4u32), MaybeUninit::new(5)];
+    let wrapped: &[MaybeUninit<Compare>] = Compare::from_maybe_uninit_slice(&buffer);
+    //safe because the buffer is initialized
+    assert!(unsafe { wrapped[1].assume_init_ref() } == &Compare(5));
+    let inner: &[MaybeUninit<u32>] = Compare::as_inner_maybe_uninit_slice(wrapped);
+    assert_eq!((inner.as_ptr(), inner.len()), (buffer.as_ptr(), 2));
+}
+
+#[test]
+fn pointers() {
+    let mut raw = 1u32;
+    let p: *mut Compare = Compare::from_ptr_mut(&mut raw);
+    //safe because `p` points to `raw`
+    unsafe { *p = Compare(2) };
+    assert_eq!(Compare::as_inner_ptr_mut(p), &mut raw as *mut u32);
+    assert_eq!(raw, 2);
+    let n = FooG::from_non_null(std::ptr::NonNull::<imp::FooG<u8>>::dangling());
+    assert_eq!(FooG::as_inner_non_null(n), std::ptr::NonNull::dangling());
+    let mut erased = vec![1u8];
+    let p: *mut dyn Indexed = &mut erased;
+    let wrapped: *const IndexUnsized = IndexUnsized::from_ptr(p);
+    //safe because `wrapped` points to `erased`
+    assert_eq!(unsafe { &*wrapped }[0], 1);
+    assert!(std::ptr::addr_eq(IndexUnsized::as_inner_ptr(wrapped), p));
+    let n = IndexUnsized::from_non_null(std::ptr::NonNull::from(&mut erased as &mut dyn Indexed));
+    assert!(std::ptr::addr_eq(IndexUnsized::as_inner_non_null(n).as_ptr(), p));
+}